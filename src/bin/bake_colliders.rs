@@ -0,0 +1,27 @@
+//! Builds the colliders of every level and writes them to the collider cache, so shipping builds
+//! never run V-HACD at load time.
+
+use bevy_game_jam_6::game::headless::HeadlessGame;
+use bevy_game_jam_6::game::levels::LEVELS;
+use bevy_game_jam_6::game::physics::collider_cache::ColliderCache;
+use itertools::Itertools;
+
+fn main() {
+    let mut cache = ColliderCache::default();
+    for level in LEVELS.iter().unique_by(|level| level.scene) {
+        println!("baking {} ({})", level.name, level.scene);
+        let mut game = HeadlessGame::with_level(level.id);
+        let level_cache = game
+            .world_mut()
+            .remove_resource::<ColliderCache>()
+            .expect("ColliderCache");
+        cache.merge_used(level_cache);
+    }
+    match cache.save() {
+        Ok(path) => println!("baked {} colliders to {}", cache.len(), path.display()),
+        Err(err) => {
+            eprintln!("failed to save collider cache: {err}");
+            std::process::exit(1);
+        }
+    }
+}
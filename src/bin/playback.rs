@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use bevy_game_jam_6::game::GamePlugin;
+use bevy_game_jam_6::game::levels::{Difficulty, Level, SelectedDifficulty, SelectedLevel};
+use bevy_game_jam_6::game::run_recording::{RunClock, RunPlayback, RunRecording};
+use bevy_game_jam_6::game::screens::Screen;
+
+fn main() {
+    let Some(path) = std::env::args().nth(1) else {
+        eprintln!("usage: playback <recording.ron>");
+        std::process::exit(2);
+    };
+    let recording = match RunRecording::load(&path) {
+        Ok(recording) => recording,
+        Err(err) => {
+            eprintln!("failed to load {path}: {err}");
+            std::process::exit(1);
+        }
+    };
+    let mut app = App::new();
+    app.add_plugins(GamePlugin);
+    app.insert_resource(SelectedLevel(Level::get(&recording.level).id));
+    app.insert_resource(SelectedDifficulty(Difficulty::get(&recording.difficulty)));
+    app.insert_resource(RunPlayback::new(recording));
+    // Skip the menus, the level loading screen waits for the assets itself.
+    app.add_systems(PostStartup, |mut next_screen: ResMut<NextState<Screen>>| {
+        next_screen.set(Screen::LoadLevel);
+    });
+    app.add_systems(
+        Update,
+        exit_when_finished.run_if(in_state(Screen::Gameplay).or(in_state(Screen::End))),
+    );
+    app.run();
+}
+
+fn exit_when_finished(
+    clock: Res<RunClock>,
+    playback: Res<RunPlayback>,
+    screen: Res<State<Screen>>,
+    mut app_exit: EventWriter<AppExit>,
+) {
+    if *screen.get() == Screen::End || playback.is_finished(clock.0) {
+        info!("playback finished at tick {}", clock.0);
+        app_exit.write(AppExit::Success);
+    }
+}
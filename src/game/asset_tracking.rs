@@ -1,8 +1,9 @@
 //! A high-level way to load collections of asset handles as resources.
 
+use std::any::TypeId;
 use std::collections::VecDeque;
 
-use bevy::prelude::*;
+use bevy::{asset::RecursiveDependencyLoadState, ecs::schedule::ScheduleLabel, prelude::*};
 
 pub(super) fn plugin(app: &mut App) {
     app.init_resource::<ResourceHandles>();
@@ -14,56 +15,173 @@ pub trait LoadResource {
     /// have been loaded, it will be inserted as a resource. This ensures that the resource only
     /// exists when the assets are ready.
     fn load_resource<T: Resource + Asset + Clone + FromWorld>(&mut self) -> &mut Self;
+
+    /// Like [`LoadResource::load_resource`], but only starts loading once `load` runs (e.g.
+    /// `OnEnter(Screen::LoadLevel)`), so nothing is fetched for screens that are never visited.
+    fn load_resource_on<T: Resource + Asset + Clone + FromWorld>(
+        &mut self,
+        load: impl ScheduleLabel,
+    ) -> &mut Self;
+
+    /// Like [`LoadResource::load_resource_on`], and removes the resource again when `unload` runs,
+    /// releasing its assets until the next time `load` runs.
+    fn load_resource_scoped<T: Resource + Asset + Clone + FromWorld>(
+        &mut self,
+        load: impl ScheduleLabel,
+        unload: impl ScheduleLabel,
+    ) -> &mut Self;
 }
 
 impl LoadResource for App {
     fn load_resource<T: Resource + Asset + Clone + FromWorld>(&mut self) -> &mut Self {
         self.init_asset::<T>();
-        let world = self.world_mut();
-        let value = T::from_world(world);
-        let assets = world.resource::<AssetServer>();
-        let handle = assets.add(value);
-        let mut handles = world.resource_mut::<ResourceHandles>();
-        handles
-            .waiting
-            .push_back((handle.untyped(), |world, handle| {
-                let assets = world.resource::<Assets<T>>();
-                if let Some(value) = assets.get(handle.id().typed::<T>()) {
-                    world.insert_resource(value.clone());
-                }
-            }));
+        queue_resource::<T>(self.world_mut());
+        self
+    }
+
+    fn load_resource_on<T: Resource + Asset + Clone + FromWorld>(
+        &mut self,
+        load: impl ScheduleLabel,
+    ) -> &mut Self {
+        self.init_asset::<T>();
+        self.add_systems(load, queue_resource::<T>);
+        self
+    }
+
+    fn load_resource_scoped<T: Resource + Asset + Clone + FromWorld>(
+        &mut self,
+        load: impl ScheduleLabel,
+        unload: impl ScheduleLabel,
+    ) -> &mut Self {
+        self.load_resource_on::<T>(load);
+        self.add_systems(unload, unload_resource::<T>);
         self
     }
 }
 
+/// Starts loading `T`, unless it's already loaded or on its way.
+fn queue_resource<T: Resource + Asset + Clone + FromWorld>(world: &mut World) {
+    if world.contains_resource::<T>()
+        || world
+            .resource::<ResourceHandles>()
+            .is_tracked(TypeId::of::<T>())
+    {
+        return;
+    }
+    let value = T::from_world(world);
+    let assets = world.resource::<AssetServer>();
+    let handle = assets.add(value);
+    let mut handles = world.resource_mut::<ResourceHandles>();
+    handles.waiting.push_back(WaitingResource {
+        handle: handle.untyped(),
+        type_name: std::any::type_name::<T>(),
+        insert: |world, handle| {
+            let assets = world.resource::<Assets<T>>();
+            if let Some(value) = assets.get(handle.id().typed::<T>()) {
+                world.insert_resource(value.clone());
+            }
+        },
+    });
+}
+
+/// Removes `T` and drops its handle, so its assets are freed once nothing else uses them.
+fn unload_resource<T: Resource + Asset>(world: &mut World) {
+    world.remove_resource::<T>();
+    world
+        .resource_mut::<ResourceHandles>()
+        .forget(TypeId::of::<T>());
+}
+
 /// A function that inserts a loaded resource.
 type InsertLoadedResource = fn(&mut World, &UntypedHandle);
 
+struct WaitingResource {
+    handle: UntypedHandle,
+    type_name: &'static str,
+    insert: InsertLoadedResource,
+}
+
+/// An asset that could not be loaded, along with what needed it.
+#[derive(Debug, Clone)]
+pub struct AssetLoadFailure {
+    /// Type name of the [`Resource`] (or asset) that depends on the failed asset.
+    pub type_name: &'static str,
+    /// The load error, which names the path of the asset that failed.
+    pub error: String,
+}
+
 #[derive(Resource, Default)]
 pub struct ResourceHandles {
     // Use a queue for waiting assets so they can be cycled through and moved to
     // `finished` one at a time.
-    waiting: VecDeque<(UntypedHandle, InsertLoadedResource)>,
+    waiting: VecDeque<WaitingResource>,
     finished: Vec<UntypedHandle>,
+    failed: Vec<AssetLoadFailure>,
 }
 
 impl ResourceHandles {
     /// Returns true if all requested [`Asset`]s have finished loading and are available as [`Resource`]s.
     pub fn is_all_done(&self) -> bool {
-        self.waiting.is_empty()
+        self.waiting.is_empty() && self.failed.is_empty()
+    }
+
+    /// Number of requested [`Asset`]s that have finished loading, out of all requested so far.
+    pub fn progress(&self) -> (usize, usize) {
+        let finished = self.finished.len();
+        (finished, finished + self.waiting.len() + self.failed.len())
+    }
+
+    fn is_tracked(&self, type_id: TypeId) -> bool {
+        self.waiting
+            .iter()
+            .map(|waiting| &waiting.handle)
+            .chain(&self.finished)
+            .any(|handle| handle.type_id() == type_id)
+    }
+
+    fn forget(&mut self, type_id: TypeId) {
+        self.waiting
+            .retain(|waiting| waiting.handle.type_id() != type_id);
+        self.finished.retain(|handle| handle.type_id() != type_id);
     }
+
+    pub fn failures(&self) -> &[AssetLoadFailure] {
+        &self.failed
+    }
+
+    /// Forgets reported failures, so the next load can try again. Their handles are already
+    /// dropped, so the assets are fetched anew.
+    pub fn clear_failures(&mut self) {
+        self.failed.clear();
+    }
+
+    /// Records a failed asset that isn't loaded through [`LoadResource`], so it's reported the same way.
+    pub fn report_failure(&mut self, type_name: &'static str, error: impl ToString) {
+        let error = error.to_string();
+        error!("failed to load {type_name}: {error}");
+        self.failed.push(AssetLoadFailure { type_name, error });
+    }
+}
+
+/// Run condition that's true once any tracked asset failed to load.
+pub fn any_asset_failed(resource_handles: Res<ResourceHandles>) -> bool {
+    !resource_handles.failed.is_empty()
 }
 
 fn load_resource_assets(world: &mut World) {
     world.resource_scope(|world, mut resource_handles: Mut<ResourceHandles>| {
         world.resource_scope(|world, assets: Mut<AssetServer>| {
             for _ in 0..resource_handles.waiting.len() {
-                let (handle, insert_fn) = resource_handles.waiting.pop_front().unwrap();
-                if assets.is_loaded_with_dependencies(&handle) {
-                    insert_fn(world, &handle);
-                    resource_handles.finished.push(handle);
-                } else {
-                    resource_handles.waiting.push_back((handle, insert_fn));
+                let waiting = resource_handles.waiting.pop_front().unwrap();
+                match assets.get_recursive_dependency_load_state(&waiting.handle) {
+                    Some(RecursiveDependencyLoadState::Loaded) => {
+                        (waiting.insert)(world, &waiting.handle);
+                        resource_handles.finished.push(waiting.handle);
+                    }
+                    Some(RecursiveDependencyLoadState::Failed(error)) => {
+                        resource_handles.report_failure(waiting.type_name, error);
+                    }
+                    _ => resource_handles.waiting.push_back(waiting),
                 }
             }
         });
@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use bevy_auto_plugin::auto_plugin::*;
 
+use crate::game::time_scale::TimeScale;
+
 /// An organizational marker component that should be added to a spawned [`AudioPlayer`] if it's in the
 /// general "music" category (e.g. global background music, soundtrack).
 ///
@@ -39,9 +41,27 @@ fn apply_global_volume(
     }
 }
 
+/// Lower bound for sound effect speed, so a hit-stop bends the pitch instead of stalling playback.
+const MIN_TIME_SCALE_SPEED: f32 = 0.05;
+
+/// Bends the pitch of playing sound effects along with the game's [`TimeScale`].
+fn apply_time_scale_speed(
+    time_scale: Res<TimeScale>,
+    audio_query: Query<(&PlaybackSettings, Ref<AudioSink>), With<SoundEffect>>,
+) {
+    let scale = time_scale.current().max(MIN_TIME_SCALE_SPEED);
+    for (playback, sink) in &audio_query {
+        if !time_scale.is_changed() && !sink.is_added() {
+            continue;
+        }
+        sink.set_speed(playback.speed * scale);
+    }
+}
+
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(
         Update,
         apply_global_volume.run_if(resource_changed::<GlobalVolume>),
     );
+    app.add_systems(Update, apply_time_scale_speed);
 }
@@ -14,7 +14,7 @@ fn update_zeus_ball_count(time: Res<Time>, mut ld: ResMut<LevelData>) {
 #[auto_plugin(app=app)]
 pub(crate) fn plugin(app: &mut App) {
     app.add_systems(
-        Update,
+        FixedUpdate,
         update_zeus_ball_count.run_if(in_state(Pause(false))),
     );
 }
@@ -2,6 +2,7 @@ use crate::game::camera::CameraTarget;
 use crate::game::pause_controller::Pause;
 use crate::game::prefabs::player::Player;
 use crate::game::screens::Screen;
+use crate::game::time_scale::ImpactFocus;
 use bevy::prelude::*;
 use bevy_auto_plugin::auto_plugin::*;
 
@@ -9,7 +10,12 @@ fn camera_target(
     mut commands: Commands,
     button_input: Res<ButtonInput<KeyCode>>,
     player: Query<Entity, With<Player>>,
+    impact_focus: Query<(), With<ImpactFocus>>,
 ) {
+    // Let slow-motion effects keep the camera on the point of impact
+    if !impact_focus.is_empty() {
+        return;
+    }
     // If space is not being held, retarget to Player
     if !button_input.pressed(KeyCode::Space) {
         if let Some(ent) = player.iter().next() {
@@ -33,5 +33,5 @@ fn despawn(mut commands: Commands, time: Res<Time>, mut despawns: Query<(Entity,
 
 #[auto_plugin(app=app)]
 pub(crate) fn plugin(app: &mut App) {
-    app.add_systems(Update, despawn.run_if(not(in_state(Pause(true)))));
+    app.add_systems(FixedUpdate, despawn.run_if(not(in_state(Pause(true)))));
 }
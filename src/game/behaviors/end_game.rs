@@ -11,5 +11,8 @@ fn condition(ld: Res<LevelData>, mut next_screen: ResMut<NextState<Screen>>) {
 
 #[auto_plugin(app=app)]
 pub(crate) fn plugin(app: &mut App) {
-    app.add_systems(Update, condition.run_if(in_state(Pause(false))));
+    app.add_systems(
+        Update,
+        condition.run_if(in_state(Pause(false)).and(in_state(Screen::Gameplay))),
+    );
 }
@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use avian3d::prelude::{Position, Rotation};
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::behaviors::despawn::Despawn;
+use crate::game::pause_controller::Pause;
+use crate::game::prefabs::enemy::{Enemy, PlayBoneSnap};
+use crate::game::scenes::LevelData;
+use crate::game::screens::Screen;
+
+/// Marks an enemy that has been knocked over and no longer counts as a threat.
+#[auto_register_type]
+#[derive(Component, Debug, Default, Copy, Clone, Reflect)]
+#[reflect(Component)]
+pub struct KnockedDown;
+
+/// Sent once per enemy when it gets knocked over.
+#[auto_register_type]
+#[auto_add_event]
+#[derive(Event, Debug, Copy, Clone, Reflect)]
+pub struct EnemyKnockedDown {
+    pub entity: Entity,
+    pub position: Vec3,
+    /// Score it was worth.
+    pub points: usize,
+    /// How far into the current combo it came, starting at 1.
+    pub combo: usize,
+}
+
+/// Score for a knockdown, multiplied by its place in the combo.
+pub const KNOCKDOWN_POINTS: usize = 100;
+
+/// Knockdowns within this long of the last one keep the combo going.
+pub const COMBO_WINDOW: Duration = Duration::from_millis(1500);
+
+/// cos(50°) - once an enemy's up axis leans further than this from world up it's down for good.
+const KNOCKDOWN_MIN_UP_DOT: f32 = 0.64;
+
+fn detect_knockdowns(
+    mut commands: Commands,
+    mut level_data: ResMut<LevelData>,
+    mut knocked_down: EventWriter<EnemyKnockedDown>,
+    enemies: Query<(Entity, &Enemy, &Position, &Rotation), Without<KnockedDown>>,
+) {
+    for (entity, enemy, position, rotation) in enemies.iter() {
+        if (rotation.0 * Vec3::Y).dot(Vec3::Y) > KNOCKDOWN_MIN_UP_DOT {
+            continue;
+        }
+        level_data.kill_count += 1;
+        level_data.combo += 1;
+        level_data.combo_time_left = COMBO_WINDOW;
+        let points = KNOCKDOWN_POINTS * level_data.combo;
+        level_data.score += points;
+        commands.entity(entity).insert((
+            KnockedDown,
+            Despawn::in_seconds(enemy.default_despawn_time()),
+        ));
+        commands.trigger(PlayBoneSnap);
+        knocked_down.write(EnemyKnockedDown {
+            entity,
+            position: position.0,
+            points,
+            combo: level_data.combo,
+        });
+    }
+}
+
+fn tick_combo(time: Res<Time>, mut level_data: ResMut<LevelData>) {
+    if level_data.combo == 0 {
+        return;
+    }
+    level_data.combo_time_left = level_data.combo_time_left.saturating_sub(time.delta());
+    if level_data.combo_time_left.is_zero() {
+        level_data.combo = 0;
+    }
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(
+        FixedUpdate,
+        (tick_combo, detect_knockdowns)
+            .chain()
+            .run_if(in_state(Pause(false)).and(in_state(Screen::Gameplay))),
+    );
+}
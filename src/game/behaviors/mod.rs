@@ -2,8 +2,10 @@ pub mod ball_counter;
 pub mod camera;
 pub mod despawn;
 pub mod end_game;
+pub mod knockdown;
 pub mod spawn;
 pub mod target_ent;
+pub mod temple_damage;
 
 use bevy::prelude::*;
 use bevy_auto_plugin::auto_plugin::*;
@@ -18,5 +20,9 @@ pub(crate) fn plugin(app: &mut App) {
     app.add_plugins(ball_counter::plugin);
     app.add_plugins(camera::plugin);
     app.add_plugins(despawn::plugin);
+    app.add_plugins(end_game::plugin);
+    app.add_plugins(knockdown::plugin);
     app.add_plugins(spawn::plugin);
+    app.add_plugins(target_ent::plugin);
+    app.add_plugins(temple_damage::plugin);
 }
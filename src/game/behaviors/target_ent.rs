@@ -1,7 +1,12 @@
-use avian3d::prelude::CollidingEntities;
+use avian3d::prelude::{CollidingEntities, LinearVelocity, Position};
 use bevy::prelude::*;
 use bevy_auto_plugin::auto_plugin::*;
 
+use crate::game::behaviors::MovementSpeed;
+use crate::game::behaviors::knockdown::KnockedDown;
+use crate::game::pause_controller::Pause;
+use crate::game::screens::Screen;
+
 #[auto_register_type]
 #[derive(Component, Debug, Copy, Clone, Reflect)]
 #[reflect(Component)]
@@ -9,3 +14,39 @@ use bevy_auto_plugin::auto_plugin::*;
 pub struct TargetEnt {
     pub target_ent: Entity,
 }
+
+/// How fast walkers get back up to speed after being bumped, in multiples of their speed per second.
+const STEERING_RESPONSIVENESS: f32 = 2.0;
+
+fn move_toward_target(
+    time: Res<Time>,
+    targets: Query<&GlobalTransform>,
+    mut walkers: Query<
+        (&TargetEnt, &MovementSpeed, &Position, &mut LinearVelocity),
+        Without<KnockedDown>,
+    >,
+) {
+    for (target_ent, movement_speed, position, mut velocity) in walkers.iter_mut() {
+        let Ok(target) = targets.get(target_ent.target_ent) else {
+            continue;
+        };
+        let desired = (target.translation() - position.0)
+            .with_y(0.0)
+            .normalize_or_zero()
+            * movement_speed.0;
+        // Steer instead of overwriting the velocity so hits still get to push walkers around.
+        let current = velocity.0.with_y(0.0);
+        let max_change = movement_speed.0 * STEERING_RESPONSIVENESS * time.delta_secs();
+        let steered = current + (desired - current).clamp_length_max(max_change);
+        velocity.0.x = steered.x;
+        velocity.0.z = steered.z;
+    }
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(
+        FixedUpdate,
+        move_toward_target.run_if(in_state(Pause(false)).and(in_state(Screen::Gameplay))),
+    );
+}
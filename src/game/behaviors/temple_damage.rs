@@ -0,0 +1,58 @@
+use avian3d::prelude::Position;
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::behaviors::knockdown::KnockedDown;
+use crate::game::behaviors::target_ent::TargetEnt;
+use crate::game::pause_controller::Pause;
+use crate::game::prefabs::enemy::Enemy;
+use crate::game::scenes::LevelData;
+use crate::game::screens::Screen;
+
+/// Horizontal distance from its target at which an enemy has made it to the temple.
+pub const TEMPLE_REACH: f32 = 30.0;
+
+/// Sent whenever an enemy reaches the temple and takes a point of its health.
+#[auto_register_type]
+#[auto_add_event]
+#[derive(Event, Debug, Copy, Clone, Reflect)]
+pub struct TempleDamaged {
+    pub enemy: Entity,
+    pub position: Vec3,
+    pub health_left: usize,
+}
+
+fn damage_temple(
+    mut commands: Commands,
+    mut level_data: ResMut<LevelData>,
+    mut temple_damaged: EventWriter<TempleDamaged>,
+    targets: Query<&GlobalTransform>,
+    enemies: Query<(Entity, &TargetEnt, &Position), (With<Enemy>, Without<KnockedDown>)>,
+) {
+    for (entity, target_ent, position) in enemies.iter() {
+        if level_data.temple_health == 0 {
+            return;
+        }
+        let Ok(target) = targets.get(target_ent.target_ent) else {
+            continue;
+        };
+        if (target.translation() - position.0).xz().length() > TEMPLE_REACH {
+            continue;
+        }
+        level_data.temple_health -= 1;
+        commands.entity(entity).despawn();
+        temple_damaged.write(TempleDamaged {
+            enemy: entity,
+            position: position.0,
+            health_left: level_data.temple_health,
+        });
+    }
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(
+        FixedUpdate,
+        damage_temple.run_if(in_state(Pause(false)).and(in_state(Screen::Gameplay))),
+    );
+}
@@ -0,0 +1,311 @@
+//! Pooled debris: bone fragments, dust puffs and sparks that get flung out of an impact point,
+//! bounce around the level for a moment and shrink away.
+//!
+//! Pieces are parked once their time is up and picked up again by the next [`SpawnDebris`], so a
+//! big chain reaction doesn't churn through hundreds of physics bodies. That's also why a piece's
+//! lifetime is its [`Debris::ttl`] rather than a [`Despawn`], which would despawn it for good.
+//!
+//! [`Despawn`]: crate::game::behaviors::despawn::Despawn
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use avian3d::prelude::{
+    AngularVelocity, Collider, ColliderDisabled, GravityScale, LinearDamping, LinearVelocity,
+    Position, RigidBody, RigidBodyDisabled, Rotation,
+};
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use rand::Rng;
+
+use crate::game::pause_controller::PausableSystems;
+use crate::game::physics::layers::GameLayer;
+use crate::game::rng::streams::{RngStream, RngStreams};
+use crate::game::screens::Screen;
+
+/// Most pieces alive at once. Past this the oldest ones get recycled early.
+pub const MAX_DEBRIS: usize = 256;
+
+#[auto_register_type]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Reflect)]
+pub enum DebrisKind {
+    BoneFragment,
+    Dust,
+    Spark,
+}
+
+impl DebrisKind {
+    fn size(self) -> f32 {
+        match self {
+            Self::BoneFragment => 0.6,
+            Self::Dust => 1.5,
+            Self::Spark => 0.25,
+        }
+    }
+
+    fn ttl(self) -> Duration {
+        match self {
+            Self::BoneFragment => Duration::from_secs(3),
+            Self::Dust => Duration::from_millis(900),
+            Self::Spark => Duration::from_millis(400),
+        }
+    }
+
+    /// Launch speed, each piece gets somewhere between half and all of it.
+    fn speed(self) -> f32 {
+        match self {
+            Self::BoneFragment => 14.0,
+            Self::Dust => 4.0,
+            Self::Spark => 25.0,
+        }
+    }
+
+    fn gravity_scale(self) -> f32 {
+        match self {
+            Self::BoneFragment | Self::Spark => 1.0,
+            // Drifts up a little.
+            Self::Dust => -0.05,
+        }
+    }
+
+    fn linear_damping(self) -> f32 {
+        match self {
+            Self::BoneFragment | Self::Spark => 0.1,
+            Self::Dust => 3.0,
+        }
+    }
+}
+
+/// Flings `count` pieces of debris out of `position`, mostly along `direction`.
+#[auto_register_type]
+#[derive(Event, Debug, Copy, Clone, Reflect)]
+pub struct SpawnDebris {
+    pub kind: DebrisKind,
+    pub position: Vec3,
+    pub direction: Vec3,
+    pub count: usize,
+}
+
+impl SpawnDebris {
+    pub fn new(kind: DebrisKind, position: Vec3, count: usize) -> Self {
+        Self {
+            kind,
+            position,
+            direction: Vec3::Y,
+            count,
+        }
+    }
+
+    pub fn with_direction(mut self, direction: Vec3) -> Self {
+        self.direction = direction.normalize_or(Vec3::Y);
+        self
+    }
+}
+
+/// A piece of debris. The mesh sits on a child so it can shrink without resizing the collider.
+#[auto_register_type]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Debris {
+    pub kind: DebrisKind,
+    pub ttl: Duration,
+    visual: Entity,
+}
+
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct DebrisAssets {
+    pub mesh: Handle<Mesh>,
+    pub bone: Handle<StandardMaterial>,
+    pub dust: Handle<StandardMaterial>,
+    pub spark: Handle<StandardMaterial>,
+}
+
+impl DebrisAssets {
+    fn material(&self, kind: DebrisKind) -> Handle<StandardMaterial> {
+        match kind {
+            DebrisKind::BoneFragment => self.bone.clone(),
+            DebrisKind::Dust => self.dust.clone(),
+            DebrisKind::Spark => self.spark.clone(),
+        }
+    }
+}
+
+impl FromWorld for DebrisAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(Cuboid::from_length(1.0));
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        Self {
+            mesh,
+            bone: materials.add(Color::srgb(0.9, 0.87, 0.78)),
+            dust: materials.add(StandardMaterial {
+                base_color: Color::srgba(0.6, 0.55, 0.45, 0.5),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            }),
+            spark: materials.add(StandardMaterial {
+                base_color: Color::srgb(1.0, 0.8, 0.3),
+                emissive: LinearRgba::rgb(8.0, 5.0, 1.0),
+                unlit: true,
+                ..default()
+            }),
+        }
+    }
+}
+
+/// A piece of debris in the [`DebrisPool`].
+///
+/// Kept here rather than read back off [`Debris`] so a piece can be recycled before the commands
+/// spawning it have been applied.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Reflect)]
+struct PooledDebris {
+    entity: Entity,
+    visual: Entity,
+}
+
+/// Every debris entity there is, parked or not.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Default, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct DebrisPool {
+    parked: Vec<PooledDebris>,
+    /// Oldest first.
+    live: VecDeque<PooledDebris>,
+}
+
+impl DebrisPool {
+    pub fn live_count(&self) -> usize {
+        self.live.len()
+    }
+
+    fn len(&self) -> usize {
+        self.parked.len() + self.live.len()
+    }
+}
+
+fn park(entity_cmds: &mut EntityCommands) {
+    entity_cmds.insert((RigidBodyDisabled, ColliderDisabled, Visibility::Hidden));
+}
+
+fn spawn_debris(
+    trigger: Trigger<SpawnDebris>,
+    mut commands: Commands,
+    mut pool: ResMut<DebrisPool>,
+    assets: Res<DebrisAssets>,
+    mut rng_streams: RngStreams,
+) {
+    let event = *trigger.event();
+    let mut rng = rng_streams.rng(RngStream::Cosmetic);
+    let direction = Dir3::new(event.direction).unwrap_or(Dir3::Y);
+    for _ in 0..event.count {
+        let mut reused = pool.parked.pop();
+        if reused.is_none() && pool.len() >= MAX_DEBRIS {
+            reused = pool.live.pop_front();
+        }
+        let PooledDebris { entity, visual } = reused.unwrap_or_else(|| {
+            let visual = commands.spawn(Mesh3d(assets.mesh.clone())).id();
+            let entity = commands
+                .spawn((
+                    Name::new("Debris"),
+                    RigidBody::Dynamic,
+                    Collider::cuboid(1.0, 1.0, 1.0),
+                    GameLayer::Debris.collision_layers(),
+                ))
+                .add_child(visual)
+                .id();
+            PooledDebris { entity, visual }
+        });
+        pool.live.push_back(PooledDebris { entity, visual });
+
+        // Somewhere in the hemisphere around `direction`.
+        let scatter = Vec3::new(
+            rng.random_range(-1.0..=1.0),
+            rng.random_range(-1.0..=1.0),
+            rng.random_range(-1.0..=1.0),
+        );
+        let launch = (direction.as_vec3() + scatter).normalize_or(direction.as_vec3())
+            * event.kind.speed()
+            * rng.random_range(0.5..=1.0);
+        let spin = Vec3::new(
+            rng.random_range(-10.0..=10.0),
+            rng.random_range(-10.0..=10.0),
+            rng.random_range(-10.0..=10.0),
+        );
+        let size = event.kind.size();
+        commands
+            .entity(entity)
+            .remove::<(RigidBodyDisabled, ColliderDisabled)>()
+            .insert((
+                Transform::from_translation(event.position).with_scale(Vec3::splat(size)),
+                Position(event.position),
+                Rotation::default(),
+                LinearVelocity(launch),
+                AngularVelocity(spin),
+                GravityScale(event.kind.gravity_scale()),
+                LinearDamping(event.kind.linear_damping()),
+                Visibility::Visible,
+                Debris {
+                    kind: event.kind,
+                    ttl: event.kind.ttl(),
+                    visual,
+                },
+            ));
+        commands.entity(visual).insert((
+            MeshMaterial3d(assets.material(event.kind)),
+            Transform::IDENTITY,
+        ));
+    }
+}
+
+/// Shrinks each piece away as it ages and parks it once it's gone.
+fn age_debris(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut pool: ResMut<DebrisPool>,
+    mut debris_q: Query<(Entity, &mut Debris), Without<RigidBodyDisabled>>,
+    mut visuals: Query<&mut Transform>,
+) {
+    for (entity, mut debris) in debris_q.iter_mut() {
+        debris.ttl = debris.ttl.saturating_sub(time.delta());
+        if debris.ttl.is_zero() {
+            park(&mut commands.entity(entity));
+            pool.live.retain(|live| live.entity != entity);
+            pool.parked.push(PooledDebris {
+                entity,
+                visual: debris.visual,
+            });
+            continue;
+        }
+        let Ok(mut transform) = visuals.get_mut(debris.visual) else {
+            continue;
+        };
+        let remaining = debris.ttl.as_secs_f32() / debris.kind.ttl().as_secs_f32();
+        transform.scale = Vec3::splat(remaining.clamp(0.0, 1.0).sqrt());
+    }
+}
+
+/// Debris lives outside the level, so it's cleared out with it.
+fn clear_debris(mut commands: Commands, mut pool: ResMut<DebrisPool>) {
+    let pool = &mut *pool;
+    for pooled in pool.parked.drain(..).chain(pool.live.drain(..)) {
+        commands.entity(pooled.entity).despawn();
+    }
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_observer(spawn_debris);
+    app.add_systems(
+        Update,
+        age_debris
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PausableSystems),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), clear_debris);
+}
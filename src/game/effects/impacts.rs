@@ -0,0 +1,74 @@
+//! Kicks off debris for the hits that matter: knockdowns, balls slamming into the level and skeles
+//! reaching the temple.
+
+use avian3d::prelude::{CollisionLayers, CollisionStarted, LinearVelocity, Position};
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::behaviors::knockdown::EnemyKnockedDown;
+use crate::game::behaviors::temple_damage::TempleDamaged;
+use crate::game::effects::debris::{DebrisKind, SpawnDebris};
+use crate::game::pause_controller::PausableSystems;
+use crate::game::physics::layers::GameLayer;
+use crate::game::prefabs::bowling_ball::{BOWLING_BALL_RADIUS, ThrownBall};
+use crate::game::screens::Screen;
+
+/// Slower balls roll onto the level without kicking anything up.
+const BALL_IMPACT_MIN_SPEED: f32 = 10.0;
+
+fn knockdown_debris(mut commands: Commands, mut knocked_down: EventReader<EnemyKnockedDown>) {
+    for event in knocked_down.read() {
+        commands.trigger(SpawnDebris::new(
+            DebrisKind::BoneFragment,
+            event.position,
+            6,
+        ));
+        commands.trigger(SpawnDebris::new(DebrisKind::Dust, event.position, 3));
+    }
+}
+
+fn ball_impact_debris(
+    mut commands: Commands,
+    mut collisions: EventReader<CollisionStarted>,
+    balls: Query<(&Position, &LinearVelocity, &GlobalTransform), With<ThrownBall>>,
+    layers: Query<&CollisionLayers>,
+) {
+    for &CollisionStarted(a, b) in collisions.read() {
+        let (ball, other) = if balls.contains(a) { (a, b) } else { (b, a) };
+        let Ok((position, velocity, global)) = balls.get(ball) else {
+            continue;
+        };
+        let hit_world = layers
+            .get(other)
+            .is_ok_and(|layers| layers.memberships.has_all(GameLayer::World));
+        if !hit_world || velocity.length() < BALL_IMPACT_MIN_SPEED {
+            continue;
+        }
+        let radius = BOWLING_BALL_RADIUS * global.scale().y;
+        let contact = position.0 - Vec3::Y * radius;
+        let direction = Vec3::Y + velocity.with_y(0.0).normalize_or_zero();
+        commands.trigger(SpawnDebris::new(DebrisKind::Spark, contact, 8).with_direction(direction));
+        commands.trigger(SpawnDebris::new(DebrisKind::Dust, contact, 2).with_direction(direction));
+    }
+}
+
+fn temple_damage_debris(mut commands: Commands, mut temple_damaged: EventReader<TempleDamaged>) {
+    for event in temple_damaged.read() {
+        commands.trigger(SpawnDebris::new(DebrisKind::Dust, event.position, 6));
+        commands.trigger(SpawnDebris::new(
+            DebrisKind::BoneFragment,
+            event.position,
+            4,
+        ));
+    }
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (knockdown_debris, ball_impact_debris, temple_damage_debris)
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PausableSystems),
+    );
+}
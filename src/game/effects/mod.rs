@@ -1,5 +1,11 @@
+pub mod debris;
+pub mod impacts;
+
 use bevy::prelude::*;
 use bevy_auto_plugin::auto_plugin::*;
 
 #[auto_plugin(app=app)]
-pub(crate) fn plugin(app: &mut App) {}
+pub(crate) fn plugin(app: &mut App) {
+    app.add_plugins(debris::plugin);
+    app.add_plugins(impacts::plugin);
+}
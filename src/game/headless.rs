@@ -0,0 +1,348 @@
+//! A windowless, rendererless and silent [`App`] that runs the gameplay simulation, so gameplay
+//! rules can be covered by `cargo test`.
+//!
+//! [`HeadlessGame`] loads the real assets and `world.glb` collision geometry, then lets a test place
+//! formations, throw balls and step the fixed timestep one tick per [`App::update`].
+
+use std::time::{Duration, Instant};
+
+use bevy::app::PluginGroupBuilder;
+use bevy::asset::AssetMetaCheck;
+use bevy::audio::{AudioLoader, AudioPlugin};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::log::LogPlugin;
+use bevy::prelude::*;
+use bevy::render::RenderPlugin;
+use bevy::render::settings::WgpuSettings;
+use bevy::time::TimeUpdateStrategy;
+use bevy::window::ExitCondition;
+use bevy::winit::WinitPlugin;
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_skein::SkeinPlugin;
+
+use crate::game::asset_tracking::ResourceHandles;
+use crate::game::behaviors::knockdown::KnockedDown;
+use crate::game::levels::{LEVELS, SelectedLevel};
+use crate::game::load_progress::LoadProgress;
+use crate::game::physics::collider_cache::collider_cache_settled;
+use crate::game::prefabs::bowling_ball::ThrownBall;
+use crate::game::prefabs::game_world::{GameWorld, load_game_world_assets};
+use crate::game::prefabs::game_world_markers::GameWorldMarkerSystemParam;
+use crate::game::prefabs::player::PlayerSystemParam;
+use crate::game::rng::run_seed::SeedSelection;
+use crate::game::rng::{RngPlugin, ZERO_SEED};
+use crate::game::scenes::LevelData;
+use crate::game::scenes::game::{AutoSpawnWaves, spawn_formation, spawn_level};
+use crate::game::screens::Screen;
+use crate::game::screens::asset_loading::all_assets_loaded;
+use crate::game::{
+    asset_tracking, behaviors, game_system_set, levels, load_progress, pause_controller, physics,
+    player_input, prefabs, scenes, time_scale,
+};
+
+/// How long loading may take before the harness gives up.
+const LOAD_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// The gameplay half of [`GamePlugin`](super::GamePlugin), without window, renderer, audio, camera
+/// or menus. Waves don't spawn on their own and every run uses [`ZERO_SEED`].
+pub struct HeadlessGamePlugin;
+
+impl Plugin for HeadlessGamePlugin {
+    #[auto_plugin(app=app)]
+    fn build(&self, app: &mut App) {
+        // Bevy
+        app.add_plugins(headless_default_plugins());
+        // Prefabs still load their sounds, they just never get played.
+        app.init_asset::<AudioSource>();
+        app.init_asset_loader::<AudioLoader>();
+        // Exactly one fixed tick per update.
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(
+            Time::<Fixed>::default().timestep(),
+        ));
+
+        // External
+        app.add_plugins(SkeinPlugin::default());
+
+        // Internal
+        app.add_plugins(RngPlugin);
+        app.add_plugins(game_system_set::plugin);
+        app.add_plugins(asset_tracking::plugin);
+        app.add_plugins(pause_controller::plugin);
+        app.add_plugins(player_input::plugin);
+        app.add_plugins(levels::plugin);
+        app.add_plugins(load_progress::plugin);
+        app.add_plugins(physics::plugin);
+        app.add_plugins(time_scale::plugin);
+        app.add_plugins(behaviors::plugin);
+        app.add_plugins(prefabs::plugin);
+        app.add_plugins(scenes::plugin);
+        app.init_state::<Screen>();
+        app.add_systems(OnEnter(Screen::LoadLevel), load_game_world_assets);
+        app.add_systems(
+            Update,
+            spawn_level.run_if(
+                in_state(Screen::LoadLevel)
+                    .and(all_assets_loaded)
+                    .and(collider_cache_settled)
+                    .and(not(any_with_component::<GameWorld>)),
+            ),
+        );
+        app.insert_resource(AutoSpawnWaves(false));
+        app.insert_resource(SeedSelection::Custom(ZERO_SEED));
+    }
+}
+
+fn headless_default_plugins() -> PluginGroupBuilder {
+    DefaultPlugins
+        .build()
+        .set(AssetPlugin {
+            meta_check: AssetMetaCheck::Never,
+            ..default()
+        })
+        .set(WindowPlugin {
+            primary_window: None,
+            exit_condition: ExitCondition::DontExit,
+            ..default()
+        })
+        .set(RenderPlugin {
+            // Keeps the render asset types around without ever creating a GPU device.
+            render_creation: WgpuSettings {
+                backends: None,
+                ..default()
+            }
+            .into(),
+            ..default()
+        })
+        .disable::<WinitPlugin>()
+        .disable::<AudioPlugin>()
+        // Tests build many apps in one process and only one of them could own the global logger.
+        .disable::<LogPlugin>()
+}
+
+/// A loaded level in [`Screen::Gameplay`], driven by hand.
+pub struct HeadlessGame {
+    pub app: App,
+}
+
+impl HeadlessGame {
+    /// Loads all assets and the first level, then enters [`Screen::Gameplay`].
+    ///
+    /// Panics if loading doesn't finish within [`LOAD_TIMEOUT`].
+    pub fn new() -> Self {
+        Self::with_level(LEVELS[0].id)
+    }
+
+    /// Like [`HeadlessGame::new`], on the [`Level`](crate::game::levels::Level) with id `level`.
+    pub fn with_level(level: &'static str) -> Self {
+        let mut app = App::new();
+        app.add_plugins(HeadlessGamePlugin);
+        app.insert_resource(SelectedLevel(level));
+        app.finish();
+        app.cleanup();
+        let mut game = Self { app };
+        game.wait_until("assets", |world| {
+            world.resource::<ResourceHandles>().is_all_done()
+        });
+        game.set_screen(Screen::LoadLevel);
+        game.wait_until("level", level_ready);
+        game.set_screen(Screen::Gameplay);
+        game
+    }
+
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    pub fn level_data(&self) -> &LevelData {
+        self.world().resource::<LevelData>()
+    }
+
+    /// Simulates `ticks` fixed timesteps.
+    pub fn step(&mut self, ticks: u32) {
+        for _ in 0..ticks {
+            self.app.update();
+        }
+    }
+
+    /// Steps until `condition` holds, for at most `max_ticks`. Returns whether it held.
+    pub fn step_until(
+        &mut self,
+        max_ticks: u32,
+        mut condition: impl FnMut(&mut World) -> bool,
+    ) -> bool {
+        for _ in 0..max_ticks {
+            self.app.update();
+            if condition(self.world_mut()) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Runs `system` once against the game world and applies its commands.
+    pub fn run<O: 'static, M>(&mut self, system: impl IntoSystem<(), O, M>) -> O {
+        self.world_mut()
+            .run_system_once(system)
+            .expect("failed to run system in headless game")
+    }
+
+    /// Spawns a formation right at the first enemy spawn marker, see [`spawn_formation`].
+    pub fn spawn_formation(&mut self, rows: usize) -> Entity {
+        self.run(
+            move |mut commands: Commands, mut game_world_marker: GameWorldMarkerSystemParam| {
+                let spawn = game_world_marker
+                    .enemy_spawns
+                    .center()
+                    .expect("level has no EnemySpawnMarker");
+                spawn_formation(
+                    &mut commands,
+                    &mut game_world_marker,
+                    "SkeleGroup(test)",
+                    rows,
+                    spawn,
+                )
+            },
+        )
+    }
+
+    /// Has Zeus throw a ball, the same way a key press would.
+    pub fn throw_ball(&mut self, power: f32, accuracy_offset_radians: f32) -> Entity {
+        let before = self.thrown_balls();
+        self.run(move |mut player_system_param: PlayerSystemParam| {
+            player_system_param.throw_ball(power, accuracy_offset_radians)
+        });
+        self.thrown_balls()
+            .into_iter()
+            .find(|entity| !before.contains(entity))
+            .expect("Zeus never let go of the ball")
+    }
+
+    fn thrown_balls(&mut self) -> Vec<Entity> {
+        let world = self.world_mut();
+        world
+            .query_filtered::<Entity, With<ThrownBall>>()
+            .iter(world)
+            .collect()
+    }
+
+    pub fn knocked_down_count(&mut self) -> usize {
+        let world = self.world_mut();
+        world
+            .query_filtered::<(), With<KnockedDown>>()
+            .iter(world)
+            .count()
+    }
+
+    pub fn screen(&self) -> Screen {
+        *self.world().resource::<State<Screen>>().get()
+    }
+
+    fn set_screen(&mut self, screen: Screen) {
+        self.world_mut()
+            .resource_mut::<NextState<Screen>>()
+            .set(screen);
+        self.app.update();
+    }
+
+    fn wait_until(&mut self, what: &str, mut condition: impl FnMut(&mut World) -> bool) {
+        let started = Instant::now();
+        while !condition(self.world_mut()) {
+            let failures = self.world().resource::<ResourceHandles>().failures();
+            assert!(
+                failures.is_empty(),
+                "headless game failed loading {what}: {failures:?}"
+            );
+            assert!(
+                started.elapsed() < LOAD_TIMEOUT,
+                "headless game timed out loading {what}"
+            );
+            // Assets load on other threads, give them a moment instead of spinning.
+            std::thread::sleep(Duration::from_millis(1));
+            self.app.update();
+        }
+    }
+}
+
+impl Default for HeadlessGame {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Every [`LoadProgress`] stage is done.
+fn level_ready(world: &mut World) -> bool {
+    world.resource::<LoadProgress>().is_ready()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::behaviors::target_ent::TargetEnt;
+    use crate::game::behaviors::temple_damage::TempleDamaged;
+    use crate::game::prefabs::enemy::Enemy;
+
+    /// One second of simulation.
+    const SECOND: u32 = 64;
+
+    /// Puts a single enemy right next to the temple.
+    fn spawn_enemy_at_temple(game: &mut HeadlessGame) -> Entity {
+        game.run(|mut game_world_marker: GameWorldMarkerSystemParam| {
+            let target_ent = game_world_marker.player_spawn.target_entity();
+            game_world_marker.spawn_in_player_spawn(
+                (Enemy::BaseSkele, TargetEnt { target_ent }),
+                Some(Transform::from_xyz(0.0, 0.0, 10.0).with_scale(Vec3::splat(4.0))),
+            )
+        })
+    }
+
+    #[test]
+    fn thrown_ball_knocks_down_formation() {
+        let mut game = HeadlessGame::new();
+        game.spawn_formation(3);
+        game.throw_ball(1.0, 0.0);
+        let knocked_down = game.step_until(10 * SECOND, |world| {
+            world
+                .query_filtered::<(), With<KnockedDown>>()
+                .iter(world)
+                .next()
+                .is_some()
+        });
+        assert!(knocked_down, "ball never knocked down a pin");
+        let count = game.knocked_down_count();
+        assert_eq!(game.level_data().kill_count, count);
+    }
+
+    #[test]
+    fn enemy_reaching_temple_damages_it() {
+        let mut game = HeadlessGame::new();
+        let health = game.level_data().temple_health;
+        let enemy = spawn_enemy_at_temple(&mut game);
+        let damaged = game.step_until(SECOND, |world| {
+            world.resource::<LevelData>().temple_health < health
+        });
+        assert!(damaged, "enemy at the temple never damaged it");
+        assert_eq!(game.level_data().temple_health, health - 1);
+        assert!(game.world().get_entity(enemy).is_err());
+        let events = game.world().resource::<Events<TempleDamaged>>();
+        let damaged_by = events
+            .get_cursor()
+            .read(events)
+            .map(|event| event.enemy)
+            .collect::<Vec<_>>();
+        assert_eq!(damaged_by, vec![enemy]);
+    }
+
+    #[test]
+    fn losing_the_temple_ends_the_run() {
+        let mut game = HeadlessGame::new();
+        game.world_mut().resource_mut::<LevelData>().temple_health = 1;
+        spawn_enemy_at_temple(&mut game);
+        game.step(SECOND);
+        assert_eq!(game.level_data().temple_health, 0);
+        assert_eq!(game.screen(), Screen::End);
+    }
+}
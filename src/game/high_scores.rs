@@ -0,0 +1,149 @@
+//! The best runs played on this machine.
+//!
+//! Every run that ends with the temple destroyed is entered into [`HighScores`], which is kept in
+//! [`HIGH_SCORES_PATH`] in the [`storage`].
+
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game::levels::SelectedLevel;
+use crate::game::rng::run_seed::{RunSeed, format_day, today};
+use crate::game::run_recording::RunPlayback;
+use crate::game::scenes::LevelData;
+use crate::game::screens::Screen;
+use crate::game::storage;
+
+/// File name in the [`storage`].
+pub const HIGH_SCORES_PATH: &str = "high_scores.ron";
+/// Runs kept in the table.
+pub const MAX_HIGH_SCORES: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+pub struct HighScore {
+    pub score: usize,
+    pub kills: usize,
+    pub wave: usize,
+    /// Seed label, see [`RunSeed::label`].
+    pub seed: String,
+    /// Id of the [`Level`](crate::game::levels::Level).
+    pub level: String,
+    /// Id of the [`Difficulty`](crate::game::levels::Difficulty).
+    pub difficulty: String,
+    /// Days since the unix epoch.
+    pub day: u64,
+}
+
+impl HighScore {
+    pub fn date(&self) -> String {
+        format_day(self.day)
+    }
+}
+
+/// Best runs first.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Default, Clone, Reflect, Serialize, Deserialize)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct HighScores {
+    pub entries: Vec<HighScore>,
+    /// Where the run that just ended placed, if it made the table. Not saved.
+    #[serde(skip)]
+    pub latest: Option<usize>,
+}
+
+impl HighScores {
+    /// Enters `high_score` into the table and returns its place, if it made it.
+    pub fn insert(&mut self, high_score: HighScore) -> Option<usize> {
+        // Ties go to the earlier run.
+        let place = self
+            .entries
+            .iter()
+            .position(|entry| entry.score < high_score.score)
+            .unwrap_or(self.entries.len());
+        if place >= MAX_HIGH_SCORES {
+            return None;
+        }
+        self.entries.insert(place, high_score);
+        self.entries.truncate(MAX_HIGH_SCORES);
+        Some(place)
+    }
+}
+
+fn load_high_scores(mut high_scores: ResMut<HighScores>) {
+    if let Some(loaded) = storage::load_ron(HIGH_SCORES_PATH) {
+        *high_scores = loaded;
+    }
+}
+
+fn record_high_score(
+    mut high_scores: ResMut<HighScores>,
+    level_data: Res<LevelData>,
+    selected_level: Res<SelectedLevel>,
+    run_seed: Res<RunSeed>,
+    playback: Option<Res<RunPlayback>>,
+) {
+    high_scores.latest = None;
+    // Watching a recording isn't a new run.
+    if playback.is_some() {
+        return;
+    }
+    high_scores.latest = high_scores.insert(HighScore {
+        score: level_data.score,
+        kills: level_data.kill_count,
+        wave: level_data.wave,
+        seed: run_seed.label(),
+        level: selected_level.0.to_string(),
+        difficulty: level_data.difficulty.id().to_string(),
+        day: today(),
+    });
+    if high_scores.latest.is_some() {
+        storage::save_ron(HIGH_SCORES_PATH, &*high_scores);
+    }
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(Startup, load_high_scores);
+    app.add_systems(OnEnter(Screen::End), record_high_score);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn high_score(score: usize) -> HighScore {
+        HighScore {
+            score,
+            kills: score / 100,
+            wave: 1,
+            seed: String::new(),
+            level: "temple".to_string(),
+            difficulty: "endless".to_string(),
+            day: 0,
+        }
+    }
+
+    #[test]
+    fn keeps_the_best_runs_in_order() {
+        let mut high_scores = HighScores::default();
+        for score in [300, 100, 200] {
+            high_scores.insert(high_score(score));
+        }
+        assert_eq!(high_scores.insert(high_score(200)), Some(2));
+        let scores = high_scores
+            .entries
+            .iter()
+            .map(|entry| entry.score)
+            .collect::<Vec<_>>();
+        assert_eq!(scores, vec![300, 200, 200, 100]);
+
+        for _ in 0..MAX_HIGH_SCORES {
+            high_scores.insert(high_score(1000));
+        }
+        assert_eq!(high_scores.entries.len(), MAX_HIGH_SCORES);
+        assert_eq!(high_scores.insert(high_score(50)), None);
+        assert_eq!(high_scores.insert(high_score(1001)), Some(0));
+    }
+}
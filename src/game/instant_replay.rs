@@ -0,0 +1,401 @@
+//! Records the poses of balls, enemies and Zeus during a throw so the last throw can be
+//! watched again without re-running the physics.
+
+use avian3d::prelude::{Position, Rotation};
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use smart_default::SmartDefault;
+
+use crate::game::camera::CameraTarget;
+use crate::game::menus::Menu;
+use crate::game::pause_controller::Pause;
+use crate::game::prefabs::bowling_ball::{BowlingBallAssets, ThrownBall};
+use crate::game::prefabs::enemy::{ENEMY_MODEL_OFFSET, Enemy, EnemyAssets};
+use crate::game::prefabs::player::Player;
+use crate::game::screens::Screen;
+use crate::game::screens::gameplay::PauseOverlay;
+
+/// Longest capture kept for a single throw, in physics ticks (12s at the default 64Hz).
+const MAX_REPLAY_TICKS: usize = 64 * 12;
+/// How fast holding the scrub keys moves through the capture, in physics ticks per second.
+const SCRUB_TICKS_PER_SEC: f32 = 64.0;
+const MIN_PLAYBACK_SPEED: f32 = 0.125;
+const MAX_PLAYBACK_SPEED: f32 = 2.0;
+
+#[derive(Debug, Copy, Clone, Reflect)]
+enum ReplayActor {
+    Ball,
+    Enemy(Enemy),
+    Player,
+}
+
+#[derive(Debug, Copy, Clone, Reflect)]
+struct ReplaySample {
+    entity: Entity,
+    actor: ReplayActor,
+    /// World space pose for balls and enemies, local pose for the player.
+    transform: Transform,
+}
+
+#[derive(Debug, Default, Clone, Reflect)]
+struct ReplayFrame {
+    samples: Vec<ReplaySample>,
+}
+
+/// Capture of the most recent throw, one frame per physics tick.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Default, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct ReplayRecorder {
+    recording: bool,
+    frames: Vec<ReplayFrame>,
+}
+
+impl ReplayRecorder {
+    pub fn has_replay(&self) -> bool {
+        !self.frames.is_empty()
+    }
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+}
+
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Clone, SmartDefault, Reflect)]
+#[reflect(Resource)]
+pub struct ReplayPlayback {
+    /// Fractional index into the recorded frames.
+    pub cursor: f32,
+    #[default(1.0)]
+    pub speed: f32,
+    #[default(true)]
+    pub playing: bool,
+    /// Menu to go back to once the replay is closed.
+    pub return_to: Menu,
+    /// Zeus' pose before the replay started, restored once it's closed.
+    player_transform: Option<(Entity, Transform)>,
+}
+
+/// Stand-in for a recorded entity while the replay is playing.
+#[auto_register_type]
+#[derive(Component, Debug, Copy, Clone, Reflect)]
+#[reflect(Component)]
+#[require(Transform)]
+#[require(Visibility)]
+struct ReplayProxy(Entity);
+
+/// Live entity hidden while its [`ReplayProxy`] is on screen.
+#[auto_register_type]
+#[derive(Component, Debug, Default, Copy, Clone, Reflect)]
+#[reflect(Component)]
+struct HiddenForReplay;
+
+fn start_recording_on_throw(
+    mut recorder: ResMut<ReplayRecorder>,
+    thrown: Query<(), Added<ThrownBall>>,
+) {
+    if thrown.is_empty() {
+        return;
+    }
+    recorder.frames.clear();
+    recorder.recording = true;
+}
+
+fn record_frame(
+    mut recorder: ResMut<ReplayRecorder>,
+    balls: Query<(Entity, &Position, &Rotation, &GlobalTransform), With<ThrownBall>>,
+    enemies: Query<(Entity, &Enemy, &Position, &Rotation, &GlobalTransform)>,
+    player: Query<(Entity, &Transform), With<Player>>,
+) {
+    if !recorder.recording {
+        return;
+    }
+    if balls.is_empty() || recorder.frames.len() >= MAX_REPLAY_TICKS {
+        recorder.recording = false;
+        return;
+    }
+    let world_pose = |position: &Position, rotation: &Rotation, gt: &GlobalTransform| {
+        Transform::from_translation(position.0)
+            .with_rotation(rotation.0)
+            .with_scale(gt.scale())
+    };
+    let balls = balls
+        .iter()
+        .map(|(entity, position, rotation, gt)| ReplaySample {
+            entity,
+            actor: ReplayActor::Ball,
+            transform: world_pose(position, rotation, gt),
+        });
+    let enemies = enemies
+        .iter()
+        .map(|(entity, enemy, position, rotation, gt)| ReplaySample {
+            entity,
+            actor: ReplayActor::Enemy(*enemy),
+            transform: world_pose(position, rotation, gt),
+        });
+    let player = player.iter().map(|(entity, transform)| ReplaySample {
+        entity,
+        actor: ReplayActor::Player,
+        transform: *transform,
+    });
+    let samples = balls.chain(enemies).chain(player).collect();
+    recorder.frames.push(ReplayFrame { samples });
+}
+
+fn clear_recording(mut recorder: ResMut<ReplayRecorder>) {
+    *recorder = ReplayRecorder::default();
+}
+
+/// Opens the replay of the last throw, returning to `return_to` once it's closed.
+pub fn open_replay(
+    recorder: &ReplayRecorder,
+    playback: &mut ReplayPlayback,
+    next_menu: &mut NextState<Menu>,
+    return_to: Menu,
+) {
+    if !recorder.has_replay() {
+        return;
+    }
+    *playback = ReplayPlayback {
+        return_to,
+        ..default()
+    };
+    next_menu.set(Menu::Replay);
+}
+
+fn open_replay_on_key(
+    recorder: Res<ReplayRecorder>,
+    mut playback: ResMut<ReplayPlayback>,
+    mut next_pause: ResMut<NextState<Pause>>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    if !recorder.has_replay() {
+        return;
+    }
+    next_pause.set(Pause(true));
+    open_replay(&recorder, &mut playback, &mut next_menu, Menu::None);
+}
+
+pub fn close_replay(playback: &ReplayPlayback, next_menu: &mut NextState<Menu>) {
+    next_menu.set(playback.return_to);
+}
+
+fn close_replay_on_key(playback: Res<ReplayPlayback>, mut next_menu: ResMut<NextState<Menu>>) {
+    close_replay(&playback, &mut next_menu);
+}
+
+fn enter_replay(
+    mut commands: Commands,
+    recorder: Res<ReplayRecorder>,
+    mut playback: ResMut<ReplayPlayback>,
+    ball_assets: Res<BowlingBallAssets>,
+    enemy_assets: Res<EnemyAssets>,
+    gltfs: Res<Assets<Gltf>>,
+    player: Query<(Entity, &Transform), With<Player>>,
+    live_actors: Query<Entity, Or<(With<ThrownBall>, With<Enemy>)>>,
+    mut pause_overlay: Query<&mut Visibility, With<PauseOverlay>>,
+) {
+    for mut visibility in pause_overlay.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+    for entity in live_actors.iter() {
+        commands
+            .entity(entity)
+            .insert((HiddenForReplay, Visibility::Hidden));
+    }
+    playback.player_transform = player
+        .iter()
+        .next()
+        .map(|(entity, transform)| (entity, *transform));
+
+    let mut spawned = HashMap::<Entity, ReplayActor>::new();
+    for sample in recorder
+        .frames
+        .iter()
+        .flat_map(|frame| frame.samples.iter())
+    {
+        if matches!(sample.actor, ReplayActor::Player) {
+            continue;
+        }
+        spawned.entry(sample.entity).or_insert(sample.actor);
+    }
+    let mut has_camera_target = false;
+    for (entity, actor) in spawned {
+        let mut proxy = commands.spawn((
+            Name::new(format!("Replay {actor:?}")),
+            ReplayProxy(entity),
+            Visibility::Hidden,
+            StateScoped(Menu::Replay),
+        ));
+        match actor {
+            ReplayActor::Ball => {
+                proxy.insert(SceneRoot(ball_assets.bowling_ball.clone()));
+                if !has_camera_target {
+                    proxy.insert(CameraTarget);
+                    has_camera_target = true;
+                }
+            }
+            ReplayActor::Enemy(enemy) => {
+                proxy.with_child((
+                    SceneRoot(enemy.scene(&enemy_assets, &gltfs)),
+                    Transform::from_translation(ENEMY_MODEL_OFFSET),
+                ));
+            }
+            ReplayActor::Player => unreachable!(),
+        }
+    }
+}
+
+fn exit_replay(
+    mut commands: Commands,
+    playback: Res<ReplayPlayback>,
+    hidden: Query<Entity, With<HiddenForReplay>>,
+    mut pause_overlay: Query<&mut Visibility, With<PauseOverlay>>,
+) {
+    for mut visibility in pause_overlay.iter_mut() {
+        *visibility = Visibility::Inherited;
+    }
+    for entity in hidden.iter() {
+        commands
+            .entity(entity)
+            .remove::<HiddenForReplay>()
+            .insert(Visibility::Inherited);
+    }
+    if let Some((entity, transform)) = playback.player_transform {
+        if let Ok(mut ec) = commands.get_entity(entity) {
+            ec.insert(transform);
+        }
+    }
+}
+
+fn replay_controls(
+    real_time: Res<Time<Real>>,
+    fixed_time: Res<Time<Fixed>>,
+    recorder: Res<ReplayRecorder>,
+    mut playback: ResMut<ReplayPlayback>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let last_frame = recorder.frame_count().saturating_sub(1) as f32;
+    let delta = real_time.delta_secs();
+    if input.just_pressed(KeyCode::Space) {
+        if playback.cursor >= last_frame {
+            playback.cursor = 0.0;
+        }
+        playback.playing = !playback.playing;
+    }
+    if input.just_pressed(KeyCode::Home) {
+        playback.cursor = 0.0;
+    }
+    if input.just_pressed(KeyCode::ArrowUp) {
+        playback.speed = (playback.speed * 2.0).min(MAX_PLAYBACK_SPEED);
+    }
+    if input.just_pressed(KeyCode::ArrowDown) {
+        playback.speed = (playback.speed * 0.5).max(MIN_PLAYBACK_SPEED);
+    }
+    let scrub = match (
+        input.pressed(KeyCode::ArrowLeft),
+        input.pressed(KeyCode::ArrowRight),
+    ) {
+        (true, false) => -1.0,
+        (false, true) => 1.0,
+        _ => 0.0,
+    };
+    if scrub != 0.0 {
+        playback.playing = false;
+        playback.cursor += scrub * SCRUB_TICKS_PER_SEC * delta;
+    } else if playback.playing {
+        let ticks_per_sec = 1.0 / fixed_time.timestep().as_secs_f32();
+        playback.cursor += playback.speed * ticks_per_sec * delta;
+        if playback.cursor >= last_frame {
+            playback.playing = false;
+        }
+    }
+    playback.cursor = playback.cursor.clamp(0.0, last_frame);
+}
+
+fn apply_replay_frame(
+    recorder: Res<ReplayRecorder>,
+    playback: Res<ReplayPlayback>,
+    mut proxies: Query<(&ReplayProxy, &mut Transform, &mut Visibility)>,
+    mut player: Query<(Entity, &mut Transform), (With<Player>, Without<ReplayProxy>)>,
+) {
+    let index = playback.cursor.floor() as usize;
+    let Some(frame) = recorder.frames.get(index) else {
+        return;
+    };
+    let next = recorder.frames.get(index + 1).unwrap_or(frame);
+    let t = playback.cursor.fract();
+    let next_samples = next
+        .samples
+        .iter()
+        .map(|sample| (sample.entity, sample.transform))
+        .collect::<HashMap<_, _>>();
+    let poses = frame
+        .samples
+        .iter()
+        .map(|sample| {
+            let from = sample.transform;
+            let to = next_samples.get(&sample.entity).copied().unwrap_or(from);
+            let pose = Transform {
+                translation: from.translation.lerp(to.translation, t),
+                rotation: from.rotation.slerp(to.rotation, t),
+                scale: from.scale.lerp(to.scale, t),
+            };
+            (sample.entity, pose)
+        })
+        .collect::<HashMap<_, _>>();
+
+    for (proxy, mut transform, mut visibility) in proxies.iter_mut() {
+        match poses.get(&proxy.0) {
+            Some(pose) => {
+                *transform = *pose;
+                *visibility = Visibility::Inherited;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+    for (entity, mut transform) in player.iter_mut() {
+        if let Some(pose) = poses.get(&entity) {
+            *transform = *pose;
+        }
+    }
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(
+        FixedLast,
+        (start_recording_on_throw, record_frame)
+            .chain()
+            .run_if(in_state(Pause(false)).and(in_state(Screen::Gameplay))),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), clear_recording);
+
+    app.add_systems(
+        Update,
+        open_replay_on_key.run_if(
+            in_state(Screen::Gameplay)
+                .and(in_state(Menu::None))
+                .and(input_just_pressed(KeyCode::KeyR)),
+        ),
+    );
+    app.add_systems(
+        Update,
+        close_replay_on_key.run_if(
+            in_state(Menu::Replay)
+                .and(input_just_pressed(KeyCode::Escape).or(input_just_pressed(KeyCode::KeyR))),
+        ),
+    );
+    app.add_systems(OnEnter(Menu::Replay), enter_replay);
+    app.add_systems(OnExit(Menu::Replay), exit_replay);
+    app.add_systems(
+        Update,
+        (replay_controls, apply_replay_frame)
+            .chain()
+            .run_if(in_state(Menu::Replay)),
+    );
+}
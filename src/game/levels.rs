@@ -0,0 +1,339 @@
+//! The level registry, and which level and difficulty the next run is played on.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::scenes::LevelData;
+use crate::game::screens::Screen;
+
+/// How a level sends its waves.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WaveSet {
+    pub time_between_waves: Duration,
+    /// Rows in the formations of the first wave.
+    pub first_wave_rows: usize,
+    /// Rows added to the formations every wave after the first.
+    pub rows_per_wave: usize,
+    pub formations_per_wave: usize,
+}
+
+impl WaveSet {
+    /// Rows per formation in `wave`, counting from 1.
+    pub fn rows(&self, wave: usize) -> usize {
+        self.first_wave_rows + self.rows_per_wave * wave.saturating_sub(1)
+    }
+}
+
+/// What it takes to play a level.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Unlock {
+    Always,
+    /// Knock down `kills` enemies in a single run of `level`.
+    Kills {
+        level: &'static str,
+        kills: usize,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Level {
+    pub id: &'static str,
+    pub name: &'static str,
+    /// Path of the glb holding the level's world, see [`GameWorld`](crate::game::prefabs::game_world::GameWorld).
+    pub scene: &'static str,
+    pub waves: WaveSet,
+    pub temple_health: usize,
+    pub unlock: Unlock,
+}
+
+pub const LEVELS: &[Level] = &[
+    Level {
+        id: "temple",
+        name: "Temple of Zeus",
+        scene: "models/world/world.glb",
+        waves: WaveSet {
+            time_between_waves: Duration::from_secs(8),
+            first_wave_rows: 4,
+            rows_per_wave: 0,
+            formations_per_wave: 1,
+        },
+        temple_health: 5,
+        unlock: Unlock::Always,
+    },
+    // Same world as the temple with tougher waves, until it gets a scene of its own.
+    Level {
+        id: "siege",
+        name: "Twilight Siege",
+        scene: "models/world/world.glb",
+        waves: WaveSet {
+            time_between_waves: Duration::from_secs(6),
+            first_wave_rows: 4,
+            rows_per_wave: 1,
+            formations_per_wave: 1,
+        },
+        temple_health: 3,
+        unlock: Unlock::Kills {
+            level: "temple",
+            kills: 20,
+        },
+    },
+];
+
+impl Level {
+    /// Looks up a level by id, falling back to the first one.
+    pub fn get(id: &str) -> &'static Level {
+        LEVELS
+            .iter()
+            .find(|level| level.id == id)
+            .unwrap_or(&LEVELS[0])
+    }
+}
+
+/// The level the next run is played on.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Copy, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct SelectedLevel(pub &'static str);
+
+impl Default for SelectedLevel {
+    fn default() -> Self {
+        Self(LEVELS[0].id)
+    }
+}
+
+impl SelectedLevel {
+    pub fn level(&self) -> &'static Level {
+        Level::get(self.0)
+    }
+}
+
+/// A preset scaling a level's own tuning, so the siege stays harder than the temple on every
+/// difficulty.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Reflect)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Endless,
+}
+
+impl Difficulty {
+    pub const ALL: [Self; 4] = [Self::Easy, Self::Normal, Self::Hard, Self::Endless];
+
+    /// Stable name used in recordings.
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::Easy => "easy",
+            Self::Normal => "normal",
+            Self::Hard => "hard",
+            Self::Endless => "endless",
+        }
+    }
+
+    /// Looks up a difficulty by id, falling back to [`Difficulty::Normal`].
+    pub fn get(id: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.id() == id)
+            .unwrap_or_default()
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Easy => "Easy",
+            Self::Normal => "Normal",
+            Self::Hard => "Hard",
+            Self::Endless => "Endless",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&d| d == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(self) -> Self {
+        let index = Self::ALL.iter().position(|&d| d == self).unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// How far an endless run has ramped up by `wave`, from 0 on the first wave toward 1. The
+    /// other presets stay at 0 and keep sending the same waves.
+    pub fn intensity(self, wave: usize) -> f32 {
+        match self {
+            Self::Endless => 1.0 - (-(wave.saturating_sub(1) as f32) / ENDLESS_RAMP_WAVES).exp(),
+            Self::Easy | Self::Normal | Self::Hard => 0.0,
+        }
+    }
+
+    /// Multiplier on the walking speed of enemies sent in `wave`.
+    pub fn enemy_speed_scale(self, wave: usize) -> f32 {
+        let base = match self {
+            Self::Easy => 0.75,
+            Self::Normal | Self::Endless => 1.0,
+            Self::Hard => 1.3,
+        };
+        base * (1.0 + ENDLESS_MAX_SPEED_UP * self.intensity(wave))
+    }
+
+    /// Time between `wave` of `waves` and the one after it.
+    pub fn time_between_waves(self, waves: &WaveSet, wave: usize) -> Duration {
+        waves
+            .time_between_waves
+            .mul_f32(1.0 - ENDLESS_MAX_WAVE_SPEED_UP * self.intensity(wave))
+    }
+
+    /// Formations sent in `wave` of `waves`.
+    pub fn formations(self, waves: &WaveSet, wave: usize) -> usize {
+        waves.formations_per_wave
+            + (ENDLESS_MAX_EXTRA_FORMATIONS as f32 * self.intensity(wave)).round() as usize
+    }
+
+    /// Formations grow by an extra row every this many waves, on top of the level's own growth.
+    fn extra_row_every(self) -> Option<usize> {
+        match self {
+            Self::Easy => None,
+            Self::Normal => Some(5),
+            Self::Hard => Some(2),
+            Self::Endless => Some(3),
+        }
+    }
+
+    /// Rows per formation in `wave` of `waves`, counting from 1.
+    pub fn rows(self, waves: &WaveSet, wave: usize) -> usize {
+        let extra = self
+            .extra_row_every()
+            .map_or(0, |every| wave.saturating_sub(1) / every);
+        waves.rows(wave) + extra
+    }
+
+    /// How long it takes Zeus to get another ball.
+    pub fn ball_regen(self) -> Duration {
+        match self {
+            Self::Easy => Duration::from_secs(6),
+            Self::Normal | Self::Endless => Duration::from_secs(8),
+            Self::Hard => Duration::from_secs(10),
+        }
+    }
+
+    pub fn temple_health(self, level: &Level) -> usize {
+        let scale = match self {
+            Self::Easy => 2.0,
+            Self::Normal | Self::Endless => 1.0,
+            Self::Hard => 0.6,
+        };
+        ((level.temple_health as f32 * scale).round() as usize).max(1)
+    }
+}
+
+/// Waves it takes an endless run to get about two thirds of the way to its full intensity.
+const ENDLESS_RAMP_WAVES: f32 = 10.0;
+/// Enemies walk this much faster at full intensity.
+const ENDLESS_MAX_SPEED_UP: f32 = 0.4;
+/// Waves come this much sooner at full intensity.
+const ENDLESS_MAX_WAVE_SPEED_UP: f32 = 0.6;
+const ENDLESS_MAX_EXTRA_FORMATIONS: usize = 2;
+
+/// The difficulty the next run is played on.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Default, Copy, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct SelectedDifficulty(pub Difficulty);
+
+/// Best results per level, used for unlocks. Kept between sessions with the persistent settings.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Default, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct LevelProgress {
+    /// Most enemies knocked down in a single run, by level id.
+    pub best_kills: HashMap<String, usize>,
+}
+
+impl LevelProgress {
+    pub fn is_unlocked(&self, level: &Level) -> bool {
+        match level.unlock {
+            Unlock::Always => true,
+            Unlock::Kills { level, kills } => {
+                self.best_kills.get(level).copied().unwrap_or_default() >= kills
+            }
+        }
+    }
+}
+
+/// Describes what's still needed to unlock `level`.
+pub fn unlock_hint(level: &Level) -> String {
+    match level.unlock {
+        Unlock::Always => String::new(),
+        Unlock::Kills { level, kills } => {
+            format!("Knock down {kills} skeles in {}", Level::get(level).name)
+        }
+    }
+}
+
+fn record_progress(
+    selected_level: Res<SelectedLevel>,
+    level_data: Res<LevelData>,
+    mut progress: ResMut<LevelProgress>,
+) {
+    let best = progress
+        .best_kills
+        .entry(selected_level.0.to_string())
+        .or_default();
+    *best = (*best).max(level_data.kill_count);
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(OnExit(Screen::Gameplay), record_progress);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn harder_presets_are_harder() {
+        let level = &LEVELS[0];
+        let waves = &level.waves;
+        assert!(Difficulty::Easy.temple_health(level) > Difficulty::Normal.temple_health(level));
+        assert!(Difficulty::Hard.temple_health(level) < Difficulty::Normal.temple_health(level));
+        assert_eq!(Difficulty::Normal.temple_health(level), level.temple_health);
+        assert!(Difficulty::Hard.rows(waves, 10) > Difficulty::Normal.rows(waves, 10));
+        assert_eq!(Difficulty::Easy.rows(waves, 10), waves.rows(10));
+        assert_eq!(Difficulty::Hard.rows(waves, 1), waves.rows(1));
+    }
+
+    #[test]
+    fn endless_ramps_up_and_the_rest_dont() {
+        let waves = &LEVELS[0].waves;
+        for difficulty in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard] {
+            assert_eq!(
+                difficulty.time_between_waves(waves, 50),
+                waves.time_between_waves
+            );
+            assert_eq!(difficulty.formations(waves, 50), waves.formations_per_wave);
+        }
+        let endless = Difficulty::Endless;
+        assert_eq!(endless.intensity(1), 0.0);
+        assert!(endless.intensity(5) < endless.intensity(10));
+        assert!(endless.intensity(1000) <= 1.0);
+        assert!(endless.time_between_waves(waves, 20) < waves.time_between_waves);
+        assert!(endless.formations(waves, 30) > waves.formations_per_wave);
+        assert!(endless.enemy_speed_scale(20) > endless.enemy_speed_scale(1));
+    }
+
+    #[test]
+    fn ids_round_trip() {
+        for difficulty in Difficulty::ALL {
+            assert_eq!(Difficulty::get(difficulty.id()), difficulty);
+            assert_eq!(difficulty.next().previous(), difficulty);
+        }
+    }
+}
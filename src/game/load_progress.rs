@@ -0,0 +1,267 @@
+//! How far along loading a level is, from the world's assets down to its render pipelines.
+//!
+//! Each stage only starts counting once the one before it is done, since later stages can't know
+//! how much work there is before that: colliders are only queued once the scene is spawned, and
+//! pipelines only once the spawned meshes are visible.
+
+use avian3d::prelude::{Collider, ColliderConstructor, ColliderConstructorHierarchy};
+use bevy::asset::RecursiveDependencyLoadState;
+use bevy::prelude::*;
+use bevy::render::{ExtractSchedule, MainWorld, RenderApp, render_resource::PipelineCache};
+use bevy::scene::SceneInstance;
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::asset_tracking::{ResourceHandles, any_asset_failed};
+use crate::game::prefabs::game_world::{GameWorld, GameWorldAssets};
+use crate::game::screens::Screen;
+
+/// Frames without any pipeline waiting to compile before they count as warmed up. New pipelines
+/// are queued as materials first become visible, so an empty queue alone doesn't mean much.
+const PIPELINE_SETTLE_FRAMES: usize = 10;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Reflect)]
+pub enum LoadStage {
+    Assets,
+    Scenes,
+    Colliders,
+    Pipelines,
+}
+
+impl LoadStage {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Assets => "Loading assets",
+            Self::Scenes => "Spawning the world",
+            Self::Colliders => "Building colliders",
+            Self::Pipelines => "Compiling shaders",
+        }
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Reflect)]
+pub struct StageProgress {
+    pub done: usize,
+    pub total: usize,
+}
+
+impl StageProgress {
+    /// A stage waiting on the one before it.
+    const NOT_STARTED: Self = Self { done: 0, total: 1 };
+
+    pub fn is_done(&self) -> bool {
+        self.done >= self.total
+    }
+
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            (self.done as f32 / self.total as f32).min(1.0)
+        }
+    }
+}
+
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct LoadProgress {
+    pub assets: StageProgress,
+    pub scenes: StageProgress,
+    pub colliders: StageProgress,
+    pub pipelines: StageProgress,
+    /// Most pipelines seen waiting at once this load.
+    peak_waiting_pipelines: usize,
+    quiet_frames: usize,
+}
+
+impl Default for LoadProgress {
+    fn default() -> Self {
+        Self {
+            assets: StageProgress::NOT_STARTED,
+            scenes: StageProgress::NOT_STARTED,
+            colliders: StageProgress::NOT_STARTED,
+            pipelines: StageProgress::NOT_STARTED,
+            peak_waiting_pipelines: 0,
+            quiet_frames: 0,
+        }
+    }
+}
+
+impl LoadProgress {
+    pub fn stages(&self) -> [(LoadStage, StageProgress); 4] {
+        [
+            (LoadStage::Assets, self.assets),
+            (LoadStage::Scenes, self.scenes),
+            (LoadStage::Colliders, self.colliders),
+            (LoadStage::Pipelines, self.pipelines),
+        ]
+    }
+
+    /// The first stage that isn't done yet.
+    pub fn current_stage(&self) -> Option<LoadStage> {
+        self.stages()
+            .into_iter()
+            .find(|(_, progress)| !progress.is_done())
+            .map(|(stage, _)| stage)
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.current_stage().is_none()
+    }
+
+    /// Overall progress from 0 to 1, every stage weighing the same.
+    pub fn fraction(&self) -> f32 {
+        let stages = self.stages();
+        stages
+            .iter()
+            .map(|(_, progress)| progress.fraction())
+            .sum::<f32>()
+            / stages.len() as f32
+    }
+}
+
+/// Render pipelines still waiting to compile, mirrored from the render world.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Default, Copy, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct PipelineWarmup {
+    /// False without a renderer, e.g. in the headless harness, where there's nothing to warm up.
+    pub tracked: bool,
+    pub waiting: usize,
+}
+
+fn extract_waiting_pipelines(
+    mut main_world: ResMut<MainWorld>,
+    pipeline_cache: Res<PipelineCache>,
+) {
+    if let Some(mut warmup) = main_world.get_resource_mut::<PipelineWarmup>() {
+        warmup.waiting = pipeline_cache.waiting_pipelines().count();
+    }
+}
+
+fn reset_load_progress(mut progress: ResMut<LoadProgress>) {
+    *progress = LoadProgress::default();
+}
+
+pub fn update_load_progress(
+    mut progress: ResMut<LoadProgress>,
+    asset_server: Res<AssetServer>,
+    mut resource_handles: ResMut<ResourceHandles>,
+    game_world_assets: Option<Res<GameWorldAssets>>,
+    scene_spawner: Res<SceneSpawner>,
+    game_worlds: Query<(), (With<GameWorld>, With<SceneRoot>)>,
+    scene_roots: Query<Option<&SceneInstance>, With<SceneRoot>>,
+    pending_colliders: Query<
+        (),
+        Or<(
+            With<ColliderConstructor>,
+            With<ColliderConstructorHierarchy>,
+        )>,
+    >,
+    colliders: Query<(), With<Collider>>,
+    warmup: Res<PipelineWarmup>,
+) {
+    let progress = &mut *progress;
+    let world_state = game_world_assets
+        .and_then(|assets| asset_server.get_recursive_dependency_load_state(&assets.scene));
+    if let Some(RecursiveDependencyLoadState::Failed(error)) = &world_state {
+        resource_handles.report_failure(std::any::type_name::<GameWorldAssets>(), error);
+        return;
+    }
+    let world_loaded = matches!(world_state, Some(RecursiveDependencyLoadState::Loaded));
+    let (finished, total) = resource_handles.progress();
+    progress.assets = StageProgress {
+        done: finished + usize::from(world_loaded),
+        total: total + 1,
+    };
+    if !progress.assets.is_done() {
+        return;
+    }
+
+    // The world also waits for the collider cache to settle before it spawns, which can take
+    // longer than the assets (e.g. fetching it on the web). There's nothing to count until then.
+    if game_worlds.is_empty() {
+        return;
+    }
+    progress.scenes = StageProgress {
+        done: scene_roots
+            .iter()
+            .flatten()
+            .filter(|instance| scene_spawner.instance_is_ready(***instance))
+            .count(),
+        total: scene_roots.iter().count(),
+    };
+    if !progress.scenes.is_done() {
+        return;
+    }
+
+    let pending = pending_colliders.iter().count();
+    progress.colliders = StageProgress {
+        done: colliders.iter().count(),
+        total: colliders.iter().count() + pending,
+    };
+    if !progress.colliders.is_done() {
+        return;
+    }
+
+    if !warmup.tracked {
+        progress.pipelines = StageProgress::default();
+        return;
+    }
+    progress.peak_waiting_pipelines = progress.peak_waiting_pipelines.max(warmup.waiting);
+    progress.quiet_frames = if warmup.waiting == 0 {
+        progress.quiet_frames + 1
+    } else {
+        0
+    };
+    progress.pipelines = StageProgress {
+        done: progress.peak_waiting_pipelines - warmup.waiting
+            + progress.quiet_frames.min(PIPELINE_SETTLE_FRAMES),
+        total: progress.peak_waiting_pipelines + PIPELINE_SETTLE_FRAMES,
+    };
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+        render_app.add_systems(ExtractSchedule, extract_waiting_pipelines);
+        app.insert_resource(PipelineWarmup {
+            tracked: true,
+            waiting: 0,
+        });
+    }
+    app.add_systems(OnEnter(Screen::LoadLevel), reset_load_progress);
+    app.add_systems(
+        Update,
+        update_load_progress.run_if(in_state(Screen::LoadLevel).and(not(any_asset_failed))),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stages_finish_in_order() {
+        let mut progress = LoadProgress::default();
+        assert_eq!(progress.current_stage(), Some(LoadStage::Assets));
+        assert_eq!(progress.fraction(), 0.0);
+
+        progress.assets = StageProgress { done: 3, total: 3 };
+        progress.scenes = StageProgress { done: 0, total: 2 };
+        assert_eq!(progress.current_stage(), Some(LoadStage::Scenes));
+        assert_eq!(progress.fraction(), 0.25);
+
+        progress.scenes = StageProgress { done: 2, total: 2 };
+        progress.colliders = StageProgress { done: 0, total: 0 };
+        progress.pipelines = StageProgress { done: 5, total: 10 };
+        assert_eq!(progress.current_stage(), Some(LoadStage::Pipelines));
+        assert!(!progress.is_ready());
+
+        progress.pipelines.done = 10;
+        assert!(progress.is_ready());
+        assert_eq!(progress.fraction(), 1.0);
+    }
+}
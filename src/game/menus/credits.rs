@@ -154,6 +154,10 @@ pub(super) fn plugin(app: &mut App) {
         go_back.run_if(in_state(Menu::Credits).and(input_just_pressed(KeyCode::Escape))),
     );
 
-    app.load_resource::<CreditsAssets>();
-    app.add_systems(OnEnter(Menu::Credits), start_credits_music);
+    // Only fetched when the credits are opened, the music starts once it's in.
+    app.load_resource_scoped::<CreditsAssets>(OnEnter(Menu::Credits), OnExit(Menu::Credits));
+    app.add_systems(
+        Update,
+        start_credits_music.run_if(in_state(Menu::Credits).and(resource_added::<CreditsAssets>)),
+    );
 }
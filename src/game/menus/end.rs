@@ -3,8 +3,14 @@ use bevy_auto_plugin::auto_plugin::*;
 
 use crate::game::asset_tracking::LoadResource;
 use crate::game::audio::music;
+use crate::game::high_scores::HighScores;
+use crate::game::menus::high_scores::high_score_table;
+use crate::game::rng::run_seed::RunSeed;
 use crate::game::{menus::Menu, scenes::LevelData, screens::Screen, theme::widget};
 
+/// Runs listed on the end menu.
+const HIGH_SCORE_ROWS: usize = 5;
+
 #[auto_register_type]
 #[derive(Resource, Asset, Clone, Reflect)]
 #[reflect(Resource)]
@@ -30,7 +36,12 @@ fn start_credits_music(mut commands: Commands, credits_music: Res<EndAssets>) {
     ));
 }
 
-fn spawn_end_menu(mut commands: Commands, ld: Res<LevelData>) {
+fn spawn_end_menu(
+    mut commands: Commands,
+    ld: Res<LevelData>,
+    run_seed: Res<RunSeed>,
+    high_scores: Res<HighScores>,
+) {
     commands.spawn((
         widget::ui_root("End"),
         GlobalZIndex(2),
@@ -46,6 +57,17 @@ fn spawn_end_menu(mut commands: Commands, ld: Res<LevelData>) {
                 TextFont::from_font_size(30.),
                 TextColor(Color::srgb(0.7, 0.769, 0.9)),
             ),
+            widget::mini_header(match high_scores.latest {
+                Some(place) => format!("Score: {} - new high score, #{}!", ld.score, place + 1),
+                None => format!("Score: {}", ld.score),
+            }),
+            widget::mini_label(format!(
+                "Wave: {}  Difficulty: {}  Seed: {}",
+                ld.wave,
+                ld.difficulty.label(),
+                run_seed.label()
+            )),
+            high_score_table(&high_scores, HIGH_SCORE_ROWS),
             widget::button("Play Again?", play_again),
             widget::button("Quit to title", quit_to_title),
         ],
@@ -72,6 +94,6 @@ fn quit_to_title(
 
 #[auto_plugin(app=app)]
 pub(super) fn plugin(app: &mut App) {
-    app.load_resource::<EndAssets>();
+    app.load_resource_scoped::<EndAssets>(OnEnter(Screen::LoadLevel), OnEnter(Screen::Title));
     app.add_systems(OnEnter(Menu::End), (spawn_end_menu, start_credits_music));
 }
@@ -0,0 +1,73 @@
+//! The high-score table shown on the main and end menus.
+
+use bevy::{ecs::spawn::SpawnWith, prelude::*, ui::Val::*};
+
+use crate::game::{
+    high_scores::HighScores,
+    levels::{Difficulty, Level},
+    theme::{palette::HEADER_TEXT, widget},
+};
+
+const COLUMNS: [&str; 7] = ["#", "Score", "Kills", "Wave", "Mode", "Seed", "Date"];
+
+/// The top `rows` runs, with the one that just ended picked out.
+pub(super) fn high_score_table(high_scores: &HighScores, rows: usize) -> impl Bundle {
+    let mut cells = Vec::new();
+    for (place, entry) in high_scores.entries.iter().take(rows).enumerate() {
+        let latest = high_scores.latest == Some(place);
+        let level = Level::get(&entry.level);
+        let difficulty = Difficulty::get(&entry.difficulty);
+        let row = [
+            format!("{}", place + 1),
+            entry.score.to_string(),
+            entry.kills.to_string(),
+            entry.wave.to_string(),
+            format!("{} {}", level.name, difficulty.label()),
+            entry.seed.clone(),
+            entry.date(),
+        ];
+        cells.extend(row.map(|text| (text, latest)));
+    }
+    (
+        Name::new("High Scores"),
+        Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Px(6.0),
+            ..default()
+        },
+        Children::spawn((
+            Spawn(widget::mini_header("High Scores")),
+            SpawnWith(move |parent: &mut ChildSpawner| {
+                if cells.is_empty() {
+                    parent.spawn(widget::mini_label(
+                        "No runs yet, go knock some skeles down!",
+                    ));
+                    return;
+                }
+                parent
+                    .spawn((
+                        Name::new("High Score Grid"),
+                        Node {
+                            display: Display::Grid,
+                            column_gap: Px(16.0),
+                            row_gap: Px(2.0),
+                            grid_template_columns: RepeatedGridTrack::auto(COLUMNS.len() as u16),
+                            ..default()
+                        },
+                    ))
+                    .with_children(|grid| {
+                        for column in COLUMNS {
+                            grid.spawn(widget::mini_label(column));
+                        }
+                        for (text, latest) in cells {
+                            let mut cell = grid.spawn(widget::mini_label(text));
+                            if latest {
+                                cell.insert(TextColor(HEADER_TEXT));
+                            }
+                        }
+                    });
+            }),
+        )),
+    )
+}
@@ -0,0 +1,119 @@
+//! The level select menu, opened by the main menu's Play button. The difficulty is picked here too.
+
+use bevy::{
+    ecs::spawn::SpawnWith, input::common_conditions::input_just_pressed, prelude::*, ui::Val::*,
+};
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::asset_tracking::ResourceHandles;
+use crate::game::levels::{LEVELS, LevelProgress, SelectedDifficulty, SelectedLevel, unlock_hint};
+use crate::game::{menus::Menu, screens::Screen, theme::widget};
+
+fn spawn_level_select_menu(mut commands: Commands, progress: Res<LevelProgress>) {
+    let levels = LEVELS
+        .iter()
+        .map(|level| (level, progress.is_unlocked(level)))
+        .collect::<Vec<_>>();
+    commands.spawn((
+        widget::ui_root("Level Select Menu"),
+        GlobalZIndex(2),
+        StateScoped(Menu::LevelSelect),
+        Children::spawn((
+            Spawn(widget::header("Select Level")),
+            Spawn(difficulty_widget()),
+            SpawnWith(move |parent: &mut ChildSpawner| {
+                for (level, unlocked) in levels {
+                    if unlocked {
+                        let id = level.id;
+                        parent.spawn(widget::button(
+                            level.name,
+                            move |_: Trigger<Pointer<Click>>,
+                                  mut selected_level: ResMut<SelectedLevel>,
+                                  resource_handles: Res<ResourceHandles>,
+                                  mut next_screen: ResMut<NextState<Screen>>| {
+                                *selected_level = SelectedLevel(id);
+                                if resource_handles.is_all_done() {
+                                    next_screen.set(Screen::LoadLevel);
+                                } else {
+                                    next_screen.set(Screen::Loading);
+                                }
+                            },
+                        ));
+                    } else {
+                        parent.spawn(widget::label(format!("{} (locked)", level.name)));
+                        parent.spawn(widget::mini_label(unlock_hint(level)));
+                    }
+                }
+            }),
+            Spawn(widget::button("Back", go_back_on_click)),
+        )),
+    ));
+}
+
+fn difficulty_widget() -> impl Bundle {
+    (
+        Name::new("Difficulty Widget"),
+        Node {
+            align_items: AlignItems::Center,
+            column_gap: Px(10.0),
+            ..default()
+        },
+        children![
+            widget::label("Difficulty"),
+            widget::button_small("<", previous_difficulty),
+            (
+                Name::new("Current Difficulty"),
+                Node {
+                    width: Px(160.0),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widget::label(""), DifficultyLabel)],
+            ),
+            widget::button_small(">", next_difficulty),
+        ],
+    )
+}
+
+fn previous_difficulty(_: Trigger<Pointer<Click>>, mut selected: ResMut<SelectedDifficulty>) {
+    selected.0 = selected.0.previous();
+}
+
+fn next_difficulty(_: Trigger<Pointer<Click>>, mut selected: ResMut<SelectedDifficulty>) {
+    selected.0 = selected.0.next();
+}
+
+#[auto_register_type]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct DifficultyLabel;
+
+fn update_difficulty_label(
+    selected: Res<SelectedDifficulty>,
+    mut label: Single<&mut Text, With<DifficultyLabel>>,
+) {
+    label.0 = selected.0.label().to_string();
+}
+
+fn go_back_on_click(_: Trigger<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
+
+#[auto_plugin(app=app)]
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::LevelSelect), spawn_level_select_menu);
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::LevelSelect).and(input_just_pressed(KeyCode::Escape))),
+    );
+    app.add_systems(
+        Update,
+        update_difficulty_label.run_if(in_state(Menu::LevelSelect).and(
+            resource_changed::<SelectedDifficulty>.or(any_match_filter::<Added<DifficultyLabel>>),
+        )),
+    );
+}
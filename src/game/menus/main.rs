@@ -3,9 +3,16 @@
 use bevy::prelude::*;
 use bevy_auto_plugin::auto_plugin::*;
 
-use crate::game::{asset_tracking::ResourceHandles, menus::Menu, screens::Screen, theme::widget};
+use crate::game::{
+    high_scores::HighScores,
+    menus::{Menu, high_scores::high_score_table},
+    theme::widget,
+};
 
-fn spawn_main_menu(mut commands: Commands) {
+/// Runs listed under the buttons.
+const HIGH_SCORE_ROWS: usize = 3;
+
+fn spawn_main_menu(mut commands: Commands, high_scores: Res<HighScores>) {
     commands.spawn((
         widget::ui_root("Main Menu"),
         GlobalZIndex(2),
@@ -14,34 +21,34 @@ fn spawn_main_menu(mut commands: Commands) {
         children![
             widget::header("Zeus Goes Bowling"),
             widget::mini_header("Loud volume warning!"),
-            widget::button("Play", enter_load_assets_or_load_level_screen),
+            widget::button("Play", open_level_select_menu),
+            widget::button("Seed", open_seed_menu),
             widget::button("Settings", open_settings_menu),
             widget::button("Show Controls", open_controls_menu),
             widget::button("Credits", open_credits_menu),
             widget::button("Exit", exit_app),
+            high_score_table(&high_scores, HIGH_SCORE_ROWS),
         ],
         #[cfg(target_family = "wasm")]
         children![
             widget::header("Zeus Goes Bowling"),
             widget::mini_header("Loud volume warning!"),
-            widget::button("Play", enter_loading_or_gameplay_screen),
+            widget::button("Play", open_level_select_menu),
+            widget::button("Seed", open_seed_menu),
             widget::button("Settings", open_settings_menu),
             widget::button("Show Controls", open_controls_menu),
             widget::button("Credits", open_credits_menu),
+            high_score_table(&high_scores, HIGH_SCORE_ROWS),
         ],
     ));
 }
 
-fn enter_load_assets_or_load_level_screen(
-    _: Trigger<Pointer<Click>>,
-    resource_handles: Res<ResourceHandles>,
-    mut next_screen: ResMut<NextState<Screen>>,
-) {
-    if resource_handles.is_all_done() {
-        next_screen.set(Screen::LoadLevel);
-    } else {
-        next_screen.set(Screen::Loading);
-    }
+fn open_level_select_menu(_: Trigger<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::LevelSelect);
+}
+
+fn open_seed_menu(_: Trigger<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Seed);
 }
 
 fn open_settings_menu(_: Trigger<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
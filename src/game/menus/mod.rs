@@ -2,8 +2,12 @@
 
 mod credits;
 mod end;
+mod high_scores;
+mod level_select;
 mod main;
 mod pause;
+mod replay;
+mod seed;
 mod settings;
 mod view_controls;
 
@@ -18,10 +22,13 @@ pub enum Menu {
     #[default]
     None,
     Main,
+    LevelSelect,
     Credits,
     ViewControls,
     Settings,
     Pause,
+    Replay,
+    Seed,
     End,
 }
 
@@ -30,8 +37,11 @@ pub(super) fn plugin(app: &mut App) {
     app.add_plugins((
         credits::plugin,
         end::plugin,
+        level_select::plugin,
         main::plugin,
         pause::plugin,
+        replay::plugin,
+        seed::plugin,
         settings::plugin,
         view_controls::plugin,
     ));
@@ -3,6 +3,7 @@
 use bevy::{input::common_conditions::input_just_pressed, prelude::*};
 use bevy_auto_plugin::auto_plugin::*;
 
+use crate::game::instant_replay::{ReplayPlayback, ReplayRecorder, open_replay};
 use crate::game::{menus::Menu, screens::Screen, theme::widget};
 
 fn spawn_pause_menu(mut commands: Commands) {
@@ -13,6 +14,7 @@ fn spawn_pause_menu(mut commands: Commands) {
         children![
             widget::header("Game paused"),
             widget::button("Continue", close_menu),
+            widget::button("Instant Replay", open_instant_replay),
             widget::button("Settings", open_settings_menu),
             widget::button("Show Controls", open_show_controls),
             widget::button("Quit to title", quit_to_title),
@@ -24,6 +26,15 @@ fn open_settings_menu(_: Trigger<Pointer<Click>>, mut next_menu: ResMut<NextStat
     next_menu.set(Menu::Settings);
 }
 
+fn open_instant_replay(
+    _: Trigger<Pointer<Click>>,
+    recorder: Res<ReplayRecorder>,
+    mut playback: ResMut<ReplayPlayback>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    open_replay(&recorder, &mut playback, &mut next_menu, Menu::Pause);
+}
+
 fn open_show_controls(_: Trigger<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
     next_menu.set(Menu::ViewControls);
 }
@@ -0,0 +1,77 @@
+//! The instant replay overlay.
+
+use bevy::{prelude::*, ui::Val::*};
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::instant_replay::{ReplayPlayback, ReplayRecorder, close_replay};
+use crate::game::{menus::Menu, theme::widget};
+
+#[auto_register_type]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct ReplayStatusLabel;
+
+fn spawn_replay_menu(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Replay Menu"),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Percent(100.0),
+            height: Percent(100.0),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::FlexEnd,
+            flex_direction: FlexDirection::Column,
+            row_gap: Px(10.0),
+            padding: UiRect::bottom(Px(20.0)),
+            ..default()
+        },
+        // Leave the mouse to the orbit camera.
+        Pickable::IGNORE,
+        GlobalZIndex(2),
+        StateScoped(Menu::Replay),
+        children![
+            widget::header("Instant Replay"),
+            (widget::label(""), ReplayStatusLabel),
+            widget::mini_label(
+                "Space: Play/Pause   Left/Right: Scrub   Up/Down: Speed   Home: Restart   Esc: Back"
+            ),
+            widget::button("Back", go_back_on_click),
+        ],
+    ));
+}
+
+fn update_replay_status_label(
+    recorder: Res<ReplayRecorder>,
+    playback: Res<ReplayPlayback>,
+    mut label: Single<&mut Text, With<ReplayStatusLabel>>,
+) {
+    let state = if playback.playing {
+        "Playing"
+    } else {
+        "Paused"
+    };
+    label.0 = format!(
+        "{state} - frame {} / {} - x{}",
+        playback.cursor.floor() as usize + 1,
+        recorder.frame_count(),
+        playback.speed,
+    );
+}
+
+fn go_back_on_click(
+    _: Trigger<Pointer<Click>>,
+    playback: Res<ReplayPlayback>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    close_replay(&playback, &mut next_menu);
+}
+
+#[auto_plugin(app=app)]
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::Replay), spawn_replay_menu);
+    app.add_systems(
+        Update,
+        update_replay_status_label
+            .run_if(in_state(Menu::Replay).and(resource_changed::<ReplayPlayback>)),
+    );
+}
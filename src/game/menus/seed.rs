@@ -0,0 +1,151 @@
+//! The seed menu, reachable from the main menu.
+//!
+//! Pick a random seed, today's daily challenge, or type one in to replay a friend's run.
+
+use bevy::{
+    input::{
+        ButtonState,
+        common_conditions::input_just_pressed,
+        keyboard::{Key, KeyboardInput},
+    },
+    prelude::*,
+};
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::rng::SEED_LEN;
+use crate::game::rng::run_seed::{
+    SeedSelection, daily_seed, format_day, format_seed, parse_seed, today,
+};
+use crate::game::{menus::Menu, theme::widget};
+
+/// Text typed into the seed menu so far.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Default, Clone, Reflect)]
+#[reflect(Resource)]
+struct TypedSeed(String);
+
+/// Longest text that can be typed in, enough for a hex seed or a short word.
+const MAX_TYPED_SEED_LEN: usize = SEED_LEN * 3;
+
+#[auto_register_type]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct SeedSelectionLabel;
+
+#[auto_register_type]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct TypedSeedLabel;
+
+fn spawn_seed_menu(mut commands: Commands, mut typed_seed: ResMut<TypedSeed>) {
+    typed_seed.0.clear();
+    commands.spawn((
+        widget::ui_root("Seed Menu"),
+        GlobalZIndex(2),
+        StateScoped(Menu::Seed),
+        children![
+            widget::header("Seed"),
+            (widget::label(""), SeedSelectionLabel),
+            widget::button("Random", select_random),
+            widget::button("Daily Challenge", select_daily),
+            widget::mini_label("Type a seed and press Enter"),
+            (widget::label(""), TypedSeedLabel),
+            widget::button("Back", go_back_on_click),
+        ],
+    ));
+}
+
+fn selection_label(selection: SeedSelection) -> String {
+    match selection {
+        SeedSelection::Random => "Next run: random seed".to_string(),
+        SeedSelection::Custom(seed) => format!("Next run: seed {}", format_seed(seed)),
+        SeedSelection::Daily => {
+            let day = today();
+            format!(
+                "Next run: daily challenge {} ({})",
+                format_day(day),
+                format_seed(daily_seed(day))
+            )
+        }
+    }
+}
+
+fn update_seed_selection_label(
+    selection: Res<SeedSelection>,
+    mut label: Single<&mut Text, With<SeedSelectionLabel>>,
+) {
+    label.0 = selection_label(*selection);
+}
+
+fn update_typed_seed_label(
+    typed_seed: Res<TypedSeed>,
+    mut label: Single<&mut Text, With<TypedSeedLabel>>,
+) {
+    label.0 = format!("> {}_", typed_seed.0);
+}
+
+fn type_seed(
+    mut keyboard_input: EventReader<KeyboardInput>,
+    mut typed_seed: ResMut<TypedSeed>,
+    mut selection: ResMut<SeedSelection>,
+) {
+    for input in keyboard_input.read() {
+        if input.state != ButtonState::Pressed {
+            continue;
+        }
+        match &input.logical_key {
+            Key::Character(text) => {
+                for c in text.chars().filter(|c| c.is_ascii_alphanumeric()) {
+                    if typed_seed.0.len() < MAX_TYPED_SEED_LEN {
+                        typed_seed.0.push(c);
+                    }
+                }
+            }
+            Key::Backspace => {
+                typed_seed.0.pop();
+            }
+            Key::Enter => {
+                if let Some(seed) = parse_seed(&typed_seed.0) {
+                    *selection = SeedSelection::Custom(seed);
+                    typed_seed.0.clear();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn select_random(_: Trigger<Pointer<Click>>, mut selection: ResMut<SeedSelection>) {
+    *selection = SeedSelection::Random;
+}
+
+fn select_daily(_: Trigger<Pointer<Click>>, mut selection: ResMut<SeedSelection>) {
+    *selection = SeedSelection::Daily;
+}
+
+fn go_back_on_click(_: Trigger<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
+
+#[auto_plugin(app=app)]
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::Seed), spawn_seed_menu);
+    app.add_systems(
+        Update,
+        (
+            go_back.run_if(input_just_pressed(KeyCode::Escape)),
+            type_seed,
+            update_seed_selection_label.run_if(
+                resource_changed::<SeedSelection>.or(any_match_filter::<Added<SeedSelectionLabel>>),
+            ),
+            update_typed_seed_label.run_if(resource_changed::<TypedSeed>),
+        )
+            .chain()
+            .run_if(in_state(Menu::Seed)),
+    );
+}
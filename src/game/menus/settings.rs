@@ -5,7 +5,10 @@
 use bevy::{audio::Volume, input::common_conditions::input_just_pressed, prelude::*, ui::Val::*};
 use bevy_auto_plugin::auto_plugin::*;
 
-use crate::game::{menus::Menu, screens::Screen, theme::prelude::*};
+use crate::game::{
+    menus::Menu, prefabs::ragdoll::RagdollSettings, screens::Screen, settings::PersistentSettings,
+    theme::prelude::*,
+};
 
 fn spawn_settings_menu(mut commands: Commands) {
     commands.spawn((
@@ -39,6 +42,22 @@ fn settings_grid() -> impl Bundle {
                 }
             ),
             global_volume_widget(),
+            (
+                widget::label("Ragdolls"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            toggle_widget("Ragdolls", RAGDOLLS),
+            (
+                widget::label("Tutorial"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                }
+            ),
+            toggle_widget("Tutorial", TUTORIAL),
         ],
     )
 }
@@ -84,6 +103,76 @@ fn raise_global_volume(_: Trigger<Pointer<Click>>, mut global_volume: ResMut<Glo
 #[reflect(Component)]
 struct GlobalVolumeLabel;
 
+/// An On/Off setting kept in the resource `R`.
+struct Toggle<R: Resource> {
+    is_on: fn(&R) -> bool,
+    set: fn(&mut R, bool),
+}
+
+impl<R: Resource> Clone for Toggle<R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R: Resource> Copy for Toggle<R> {}
+
+const RAGDOLLS: Toggle<RagdollSettings> = Toggle {
+    is_on: |ragdolls| ragdolls.enabled,
+    set: |ragdolls, on| ragdolls.enabled = on,
+};
+
+/// Turning it back on shows the tutorial again at the start of the next level.
+const TUTORIAL: Toggle<PersistentSettings> = Toggle {
+    is_on: |settings| !settings.skip_tutorial,
+    set: |settings, on| settings.skip_tutorial = !on,
+};
+
+/// Shows whether a [`Toggle`] is on.
+#[derive(Component)]
+struct ToggleLabel<R: Resource>(Toggle<R>);
+
+fn toggle_widget<R: Resource>(name: &'static str, toggle: Toggle<R>) -> impl Bundle {
+    let flip = move |_: Trigger<Pointer<Click>>, mut resource: ResMut<R>| {
+        let on = (toggle.is_on)(&resource);
+        (toggle.set)(&mut resource, !on);
+    };
+    (
+        Name::new(format!("{name} Widget")),
+        Node {
+            justify_self: JustifySelf::Start,
+            ..default()
+        },
+        children![
+            widget::button_small("<", flip),
+            (
+                Name::new(format!("Current {name}")),
+                Node {
+                    padding: UiRect::horizontal(Px(10.0)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widget::label(""), ToggleLabel(toggle))],
+            ),
+            widget::button_small(">", flip),
+        ],
+    )
+}
+
+fn update_toggle_labels<R: Resource>(
+    resource: Res<R>,
+    mut labels: Query<(&ToggleLabel<R>, &mut Text)>,
+) {
+    for (ToggleLabel(toggle), mut text) in labels.iter_mut() {
+        text.0 = if (toggle.is_on)(&resource) {
+            "On"
+        } else {
+            "Off"
+        }
+        .to_string();
+    }
+}
+
 fn update_global_volume_label(
     global_volume: Res<GlobalVolume>,
     mut label: Single<&mut Text, With<GlobalVolumeLabel>>,
@@ -122,6 +211,11 @@ pub(super) fn plugin(app: &mut App) {
 
     app.add_systems(
         Update,
-        update_global_volume_label.run_if(in_state(Menu::Settings)),
+        (
+            update_global_volume_label,
+            update_toggle_labels::<RagdollSettings>,
+            update_toggle_labels::<PersistentSettings>,
+        )
+            .run_if(in_state(Menu::Settings)),
     );
 }
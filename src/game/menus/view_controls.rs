@@ -28,10 +28,12 @@ fn settings_grid() -> impl Bundle {
             ..default()
         },
         children![
-            widget::label("Aim: A/D"),
-            widget::label("Fire: Space"),
-            widget::mini_header("-- Cheats --"),
-            widget::label("Change Ball Speed: Up/Down Arrow"),
+            widget::label("Turn: A/D"),
+            widget::label("Aim: Left/Right Arrow"),
+            widget::label("Throw Power: Up/Down Arrow"),
+            widget::label("Turn Speed: W/S"),
+            widget::label("Throw: Space"),
+            widget::label("Instant Replay: R"),
         ],
     )
 }
@@ -6,14 +6,25 @@ mod camera;
 mod dev;
 mod effects;
 mod game_system_set;
+pub mod headless;
+mod high_scores;
+mod instant_replay;
+pub mod levels;
+mod load_progress;
 mod menus;
 mod pause_controller;
-mod physics;
+pub mod physics;
+mod player_input;
 mod prefabs;
 mod rng;
+pub mod run_recording;
 mod scenes;
 pub mod screens;
+mod settings;
+mod storage;
 mod theme;
+mod time_scale;
+mod tutorial;
 
 use crate::game::rng::RngPlugin;
 use bevy::app::PluginGroupBuilder;
@@ -52,15 +63,24 @@ impl Plugin for GamePlugin {
         app.add_plugins(dev::plugin);
         app.add_plugins(asset_tracking::plugin);
         app.add_plugins(pause_controller::plugin);
+        app.add_plugins(player_input::plugin);
+        app.add_plugins(levels::plugin);
+        app.add_plugins(load_progress::plugin);
+        app.add_plugins(run_recording::plugin);
         app.add_plugins(physics::plugin);
+        app.add_plugins(time_scale::plugin);
         app.add_plugins(behaviors::plugin);
         app.add_plugins(effects::plugin);
         app.add_plugins(prefabs::plugin);
         app.add_plugins(scenes::plugin);
+        app.add_plugins(instant_replay::plugin);
         app.add_plugins(audio::plugin);
         app.add_plugins(theme::plugin);
         app.add_plugins(menus::plugin);
         app.add_plugins(screens::plugin);
+        app.add_plugins(settings::plugin);
+        app.add_plugins(high_scores::plugin);
+        app.add_plugins(tutorial::plugin);
     }
 }
 
@@ -0,0 +1,464 @@
+//! Caches colliders built for [`AutoColliderMesh`](crate::game::prefabs::game_world_markers::AutoColliderMesh)
+//! so V-HACD only ever runs once per mesh.
+//!
+//! Colliders are keyed by a hash of the mesh's positions and indices plus the construction method.
+//! Native builds write newly built colliders back to [`COLLIDER_CACHE_PATH`] in the assets folder,
+//! and the `bake_colliders` binary pre-bakes every level for shipping builds.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use avian3d::prelude::{Collider, ColliderConstructor, FillMode, VhacdParameters};
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext, RecursiveDependencyLoadState};
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, VertexAttributeValues};
+use bevy_auto_plugin::auto_plugin::*;
+use serde::{Deserialize, Serialize};
+
+/// Path of the cache, relative to the assets folder.
+pub const COLLIDER_CACHE_PATH: &str = "colliders/baked.colliders.ron";
+/// Bump whenever [`BakedCollider`] or the mesh hash changes, older caches are then ignored.
+const COLLIDER_CACHE_VERSION: u32 = 3;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BakedConvexPart {
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub points: Vec<Vec3>,
+}
+
+/// The shape data of a built collider, enough to rebuild it without running the original method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BakedCollider {
+    ConvexHull(Vec<Vec3>),
+    Compound(Vec<BakedConvexPart>),
+    TriMesh {
+        vertices: Vec<Vec3>,
+        indices: Vec<[u32; 3]>,
+    },
+}
+
+fn point_to_vec3(point: &avian3d::parry::math::Point<f32>) -> Vec3 {
+    Vec3::new(point.x, point.y, point.z)
+}
+
+impl BakedCollider {
+    pub fn from_collider(collider: &Collider) -> Option<Self> {
+        let shape = collider.shape();
+        if let Some(compound) = shape.as_compound() {
+            let parts = compound
+                .shapes()
+                .iter()
+                .map(|(isometry, part)| {
+                    let rotation = isometry.rotation;
+                    Some(BakedConvexPart {
+                        translation: Vec3::new(
+                            isometry.translation.x,
+                            isometry.translation.y,
+                            isometry.translation.z,
+                        ),
+                        rotation: Quat::from_xyzw(rotation.i, rotation.j, rotation.k, rotation.w),
+                        points: part
+                            .as_convex_polyhedron()?
+                            .points()
+                            .iter()
+                            .map(point_to_vec3)
+                            .collect(),
+                    })
+                })
+                .collect::<Option<Vec<_>>>()?;
+            return Some(Self::Compound(parts));
+        }
+        if let Some(polyhedron) = shape.as_convex_polyhedron() {
+            return Some(Self::ConvexHull(
+                polyhedron.points().iter().map(point_to_vec3).collect(),
+            ));
+        }
+        if let Some(trimesh) = shape.as_trimesh() {
+            return Some(Self::TriMesh {
+                vertices: trimesh.vertices().iter().map(point_to_vec3).collect(),
+                indices: trimesh.indices().to_vec(),
+            });
+        }
+        None
+    }
+
+    pub fn to_collider(&self) -> Option<Collider> {
+        match self {
+            Self::ConvexHull(points) => Collider::convex_hull(points.clone()),
+            Self::Compound(parts) => {
+                let parts = parts
+                    .iter()
+                    .map(|part| {
+                        Some((
+                            part.translation,
+                            part.rotation,
+                            Collider::convex_hull(part.points.clone())?,
+                        ))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(Collider::compound(parts))
+            }
+            Self::TriMesh { vertices, indices } => {
+                Some(Collider::trimesh(vertices.clone(), indices.clone()))
+            }
+        }
+    }
+}
+
+/// Hashes the method along with its parameters, field by field so the key doesn't change with
+/// avian's `Debug` output. `None` for constructors that don't build from a mesh.
+fn hash_constructor(hash: u64, constructor: &ColliderConstructor) -> Option<u64> {
+    let hash = match constructor {
+        ColliderConstructor::TrimeshFromMesh => fnv1a(hash, &[0]),
+        ColliderConstructor::TrimeshFromMeshWithConfig(flags) => {
+            fnv1a(fnv1a(hash, &[1]), &flags.bits().to_le_bytes())
+        }
+        ColliderConstructor::ConvexDecompositionFromMesh => fnv1a(hash, &[2]),
+        ColliderConstructor::ConvexDecompositionFromMeshWithConfig(parameters) => {
+            hash_vhacd_parameters(fnv1a(hash, &[3]), parameters)
+        }
+        ColliderConstructor::ConvexHullFromMesh => fnv1a(hash, &[4]),
+        _ => return None,
+    };
+    Some(hash)
+}
+
+fn hash_vhacd_parameters(hash: u64, parameters: &VhacdParameters) -> u64 {
+    let fill_mode: &[u8] = match parameters.fill_mode {
+        FillMode::SurfaceOnly => &[0],
+        FillMode::FloodFill {
+            detect_cavities, ..
+        } => &[1, detect_cavities as u8],
+    };
+    [
+        &parameters.concavity.to_bits().to_le_bytes()[..],
+        &parameters.alpha.to_bits().to_le_bytes(),
+        &parameters.beta.to_bits().to_le_bytes(),
+        &parameters.resolution.to_le_bytes(),
+        &parameters.plane_downsampling.to_le_bytes(),
+        &parameters.convex_hull_downsampling.to_le_bytes(),
+        fill_mode,
+        &[parameters.convex_hull_approximation as u8],
+        &parameters.max_convex_hulls.to_le_bytes(),
+    ]
+    .into_iter()
+    .fold(hash, fnv1a)
+}
+
+/// FNV-1a, stable across platforms and releases unlike std's hashers.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Identifies the collider `constructor` builds from `mesh`. `None` for meshes without usable
+/// positions.
+pub fn collider_key(mesh: &Mesh, constructor: &ColliderConstructor) -> Option<u64> {
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        return None;
+    };
+    let mut hash = hash_constructor(0xcbf2_9ce4_8422_2325, constructor)?;
+    for position in positions {
+        for coord in position {
+            hash = fnv1a(hash, &coord.to_bits().to_le_bytes());
+        }
+    }
+    match mesh.indices() {
+        Some(Indices::U16(indices)) => {
+            for index in indices {
+                hash = fnv1a(hash, &u32::from(*index).to_le_bytes());
+            }
+        }
+        Some(Indices::U32(indices)) => {
+            for index in indices {
+                hash = fnv1a(hash, &index.to_le_bytes());
+            }
+        }
+        None => {}
+    }
+    Some(hash)
+}
+
+/// The on disk format of the cache.
+#[derive(Asset, TypePath, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct BakedColliders {
+    pub version: u32,
+    pub colliders: BTreeMap<u64, BakedCollider>,
+}
+
+#[derive(Debug)]
+pub enum ColliderCacheError {
+    Io(std::io::Error),
+    Parse(ron::error::SpannedError),
+    Serialize(ron::Error),
+}
+
+impl Display for ColliderCacheError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "io error: {err}"),
+            Self::Parse(err) => write!(f, "invalid collider cache: {err}"),
+            Self::Serialize(err) => write!(f, "failed to serialize collider cache: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ColliderCacheError {}
+
+impl From<std::io::Error> for ColliderCacheError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ron::error::SpannedError> for ColliderCacheError {
+    fn from(err: ron::error::SpannedError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl From<ron::Error> for ColliderCacheError {
+    fn from(err: ron::Error) -> Self {
+        Self::Serialize(err)
+    }
+}
+
+#[derive(Default)]
+struct BakedCollidersLoader;
+
+impl AssetLoader for BakedCollidersLoader {
+    type Asset = BakedColliders;
+    type Settings = ();
+    type Error = ColliderCacheError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["colliders.ron"]
+    }
+}
+
+/// Colliders built so far, keyed by [`collider_key`].
+#[derive(Resource, Debug, Default)]
+pub struct ColliderCache {
+    colliders: BTreeMap<u64, BakedCollider>,
+    /// Keys looked up or added since startup.
+    used: HashSet<u64>,
+    /// Holds colliders not written to disk yet.
+    dirty: bool,
+    handle: Handle<BakedColliders>,
+    /// The cache on disk was merged in, or there is none.
+    settled: bool,
+}
+
+impl ColliderCache {
+    pub fn get(&mut self, key: u64) -> Option<&BakedCollider> {
+        self.used.insert(key);
+        self.colliders.get(&key)
+    }
+
+    pub fn insert(&mut self, key: u64, baked: BakedCollider) {
+        self.used.insert(key);
+        self.colliders.insert(key, baked);
+        self.dirty = true;
+    }
+
+    pub fn len(&self) -> usize {
+        self.colliders.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colliders.is_empty()
+    }
+
+    /// Takes over every collider `other` looked up or built, e.g. to combine the caches of
+    /// several levels without carrying over stale colliders.
+    pub fn merge_used(&mut self, other: ColliderCache) {
+        for key in &other.used {
+            if let Some(baked) = other.colliders.get(key) {
+                self.insert(*key, baked.clone());
+            }
+        }
+    }
+
+    /// Writes the cache to [`COLLIDER_CACHE_PATH`] in the assets folder.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn save(&mut self) -> Result<std::path::PathBuf, ColliderCacheError> {
+        let path = bevy::asset::io::file::FileAssetReader::get_base_path()
+            .join("assets")
+            .join(COLLIDER_CACHE_PATH);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let baked = BakedColliders {
+            version: COLLIDER_CACHE_VERSION,
+            colliders: self.colliders.clone(),
+        };
+        let contents = ron::ser::to_string_pretty(&baked, ron::ser::PrettyConfig::default())?;
+        std::fs::write(&path, contents)?;
+        self.dirty = false;
+        Ok(path)
+    }
+}
+
+/// Run condition that's true once the cache on disk was merged in, or failed to load.
+pub fn collider_cache_settled(cache: Res<ColliderCache>) -> bool {
+    cache.settled
+}
+
+/// A collider is being built by avian for the mesh with this [`collider_key`], cache it once it's there.
+#[auto_register_type]
+#[derive(Component, Debug, Copy, Clone, Reflect)]
+#[reflect(Component)]
+pub struct PendingColliderBake(pub u64);
+
+fn load_collider_cache(asset_server: Res<AssetServer>, mut cache: ResMut<ColliderCache>) {
+    cache.handle = asset_server.load(COLLIDER_CACHE_PATH);
+}
+
+fn merge_collider_cache(
+    asset_server: Res<AssetServer>,
+    mut baked_colliders: ResMut<Assets<BakedColliders>>,
+    mut cache: ResMut<ColliderCache>,
+) {
+    let cache = &mut *cache;
+    match asset_server.get_recursive_dependency_load_state(&cache.handle) {
+        Some(RecursiveDependencyLoadState::Loaded) => {
+            if let Some(baked) = baked_colliders.remove(&cache.handle) {
+                if baked.version == COLLIDER_CACHE_VERSION {
+                    info!("loaded {} cached colliders", baked.colliders.len());
+                    for (key, collider) in baked.colliders {
+                        cache.colliders.entry(key).or_insert(collider);
+                    }
+                } else {
+                    warn!(
+                        "ignoring collider cache v{} (expected v{COLLIDER_CACHE_VERSION})",
+                        baked.version
+                    );
+                }
+            }
+            cache.settled = true;
+        }
+        Some(RecursiveDependencyLoadState::Failed(error)) => {
+            info!("no collider cache: {error}");
+            cache.settled = true;
+        }
+        _ => {}
+    }
+}
+
+fn collect_built_colliders(
+    mut commands: Commands,
+    mut cache: ResMut<ColliderCache>,
+    built: Query<(Entity, &Collider, &PendingColliderBake), Added<Collider>>,
+) {
+    for (entity, collider, pending) in built.iter() {
+        commands.entity(entity).remove::<PendingColliderBake>();
+        match BakedCollider::from_collider(collider) {
+            Some(baked) => cache.insert(pending.0, baked),
+            None => warn!("can't cache the collider of {entity}"),
+        }
+    }
+}
+
+/// Writes newly built colliders to disk while developing, so the next run doesn't rebuild them.
+/// Release builds never write it, the shipped cache comes from `bin/bake_colliders.rs`.
+#[cfg(all(feature = "dev", not(target_family = "wasm")))]
+pub fn save_collider_cache(mut cache: ResMut<ColliderCache>) {
+    if !cache.dirty {
+        return;
+    }
+    match cache.save() {
+        Ok(path) => info!("saved {} colliders to {}", cache.len(), path.display()),
+        Err(err) => error!("failed to save collider cache: {err}"),
+    }
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.init_asset::<BakedColliders>();
+    app.init_asset_loader::<BakedCollidersLoader>();
+    app.init_resource::<ColliderCache>();
+    app.add_systems(Startup, load_collider_cache);
+    app.add_systems(
+        PreUpdate,
+        merge_collider_cache.run_if(not(collider_cache_settled)),
+    );
+    app.add_systems(Update, collect_built_colliders);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn baked_compound_rebuilds_the_same_parts() {
+        let collider = Collider::compound(vec![
+            (
+                Vec3::X,
+                Quat::IDENTITY,
+                Collider::convex_hull(vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z]).unwrap(),
+            ),
+            (
+                Vec3::NEG_X,
+                Quat::from_rotation_y(1.0),
+                Collider::convex_hull(vec![Vec3::ZERO, Vec3::NEG_X, Vec3::Y, Vec3::Z]).unwrap(),
+            ),
+        ]);
+        let baked = BakedCollider::from_collider(&collider).unwrap();
+        let rebuilt = BakedCollider::from_collider(&baked.to_collider().unwrap()).unwrap();
+        let (BakedCollider::Compound(baked_parts), BakedCollider::Compound(rebuilt_parts)) =
+            (&baked, &rebuilt)
+        else {
+            panic!("expected compounds, got {baked:?} and {rebuilt:?}");
+        };
+        assert_eq!(rebuilt_parts.len(), 2);
+        for (baked, rebuilt) in baked_parts.iter().zip(rebuilt_parts) {
+            assert_eq!(rebuilt.translation, baked.translation);
+            assert!(rebuilt.rotation.abs_diff_eq(baked.rotation, 1e-6));
+            // The hull may list its points in another order.
+            assert_eq!(rebuilt.points.len(), baked.points.len());
+        }
+        let serialized = ron::to_string(&baked).unwrap();
+        assert_eq!(ron::from_str::<BakedCollider>(&serialized).unwrap(), baked);
+    }
+
+    #[test]
+    fn key_covers_the_vhacd_parameters() {
+        let mesh = Mesh::from(Cuboid::default());
+        let vhacd = ColliderConstructor::ConvexDecompositionFromMeshWithConfig;
+        let defaults = collider_key(&mesh, &vhacd(VhacdParameters::default()));
+        assert!(defaults.is_some());
+        assert_eq!(
+            defaults,
+            collider_key(&mesh, &vhacd(VhacdParameters::default()))
+        );
+        let coarser = VhacdParameters {
+            resolution: 32,
+            ..default()
+        };
+        assert_ne!(defaults, collider_key(&mesh, &vhacd(coarser)));
+        assert_ne!(
+            defaults,
+            collider_key(&mesh, &ColliderConstructor::ConvexDecompositionFromMesh)
+        );
+        assert_eq!(
+            collider_key(&mesh, &ColliderConstructor::Sphere { radius: 1.0 }),
+            None
+        );
+    }
+}
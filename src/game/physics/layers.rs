@@ -0,0 +1,96 @@
+//! The collision layer scheme. Every collider gets exactly one [`GameLayer`] as its membership.
+
+use avian3d::prelude::{CollisionLayers, LayerMask, PhysicsLayer};
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+
+#[auto_register_type]
+#[derive(PhysicsLayer, Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Reflect)]
+pub enum GameLayer {
+    /// Static level geometry.
+    #[default]
+    World,
+    Ball,
+    Enemy,
+    /// Zeus, who balls are spawned inside of.
+    Player,
+    Temple,
+    /// Sensors and trigger volumes, only balls set them off.
+    Trigger,
+    /// Cosmetic ragdoll limbs. They only land on the level so they never change how a run plays.
+    Ragdoll,
+    /// Effect debris, which like ragdolls only ever lands on the level.
+    Debris,
+}
+
+impl GameLayer {
+    /// The layers this one collides with. Kept symmetric, avian only lets two colliders interact
+    /// when each one's filters contain the other's membership.
+    pub fn filters(self) -> &'static [GameLayer] {
+        use GameLayer::*;
+        match self {
+            World => &[Ball, Enemy, Player, Ragdoll, Debris],
+            Ball => &[World, Ball, Enemy, Temple, Trigger],
+            Enemy => &[World, Ball, Enemy, Temple],
+            Player => &[World],
+            Temple => &[Ball, Enemy],
+            Trigger => &[Ball],
+            Ragdoll => &[World],
+            Debris => &[World],
+        }
+    }
+
+    pub fn collision_layers(self) -> CollisionLayers {
+        let filters = self.filters().iter().fold(LayerMask::NONE, |mask, layer| {
+            mask | LayerMask(layer.to_bits())
+        });
+        CollisionLayers::new(self, filters)
+    }
+}
+
+impl From<GameLayer> for CollisionLayers {
+    fn from(layer: GameLayer) -> Self {
+        layer.collision_layers()
+    }
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_are_symmetric() {
+        let all = [
+            GameLayer::World,
+            GameLayer::Ball,
+            GameLayer::Enemy,
+            GameLayer::Player,
+            GameLayer::Temple,
+            GameLayer::Trigger,
+            GameLayer::Ragdoll,
+            GameLayer::Debris,
+        ];
+        for a in all {
+            for b in all {
+                assert_eq!(
+                    a.collision_layers().interacts_with(b.collision_layers()),
+                    b.collision_layers().interacts_with(a.collision_layers()),
+                    "{a:?} and {b:?}"
+                );
+                assert_eq!(
+                    a.collision_layers().interacts_with(b.collision_layers()),
+                    a.filters().contains(&b),
+                    "{a:?} and {b:?}"
+                );
+            }
+        }
+        assert!(
+            !GameLayer::Ball
+                .collision_layers()
+                .interacts_with(GameLayer::Player.collision_layers())
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! Named physics material presets, for level surfaces in Blender (via Skein) and for prefabs.
+//!
+//! A preset only fills in what the entity doesn't already have, so an explicit [`Friction`],
+//! [`Restitution`] or [`Mass`] always wins over it.
+
+use avian3d::prelude::{CoefficientCombine, Friction, Mass, Restitution};
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+
+#[auto_register_type]
+#[derive(Component, Debug, Default, Copy, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum PhysicsMaterial {
+    /// Plain floor, what most of the level is made of.
+    #[default]
+    Lane,
+    /// Barely any grip, whatever touches it.
+    Ice,
+    /// Soaks up speed and bounce.
+    Mud,
+    /// Bounces everything back.
+    Bumper,
+    /// Enemies, set up like bowling pins.
+    Pin,
+    /// Bowling balls.
+    Ball,
+}
+
+impl PhysicsMaterial {
+    pub fn friction(self) -> Friction {
+        match self {
+            Self::Lane | Self::Pin | Self::Ball => Friction::new(0.4),
+            Self::Ice => Friction::new(0.02).with_combine_rule(CoefficientCombine::Min),
+            Self::Mud => Friction::new(2.0).with_combine_rule(CoefficientCombine::Max),
+            Self::Bumper => Friction::new(0.2),
+        }
+    }
+
+    pub fn restitution(self) -> Option<Restitution> {
+        match self {
+            Self::Lane | Self::Ice | Self::Pin => None,
+            Self::Mud => Some(Restitution::ZERO.with_combine_rule(CoefficientCombine::Min)),
+            Self::Bumper => Some(Restitution::new(0.9).with_combine_rule(CoefficientCombine::Max)),
+            Self::Ball => Some(Restitution::new(0.001)),
+        }
+    }
+
+    /// Mass of dynamic bodies made of this material, static surfaces don't need one.
+    pub fn mass(self) -> Option<Mass> {
+        match self {
+            Self::Pin => Some(Mass(1.0)),
+            Self::Ball => Some(Mass(5.0)),
+            Self::Lane | Self::Ice | Self::Mud | Self::Bumper => None,
+        }
+    }
+}
+
+fn on_physics_material_added(
+    trigger: Trigger<OnAdd, PhysicsMaterial>,
+    materials: Query<&PhysicsMaterial>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let Ok(&material) = materials.get(entity) else {
+        return;
+    };
+    let mut entity_cmds = commands.entity(entity);
+    entity_cmds.insert_if_new(material.friction());
+    if let Some(restitution) = material.restitution() {
+        entity_cmds.insert_if_new(restitution);
+    }
+    if let Some(mass) = material.mass() {
+        entity_cmds.insert_if_new(mass);
+    }
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_observer(on_physics_material_added);
+}
@@ -1,3 +1,7 @@
+pub mod collider_cache;
+pub mod layers;
+pub mod materials;
+
 use crate::game::pause_controller::Pause;
 use avian3d::prelude::{
     Physics, PhysicsInterpolationPlugin, PhysicsPickingPlugin, PhysicsPlugins, PhysicsTime,
@@ -31,6 +35,9 @@ pub(crate) fn plugin(app: &mut App) {
     app.add_plugins(PhysicsPlugins::default().set(PhysicsInterpolationPlugin::extrapolate_all()));
     app.add_plugins(PhysicsPickingPlugin);
     app.add_plugins(PhysicsDebugPlugin::default());
+    app.add_plugins(collider_cache::plugin);
+    app.add_plugins(layers::plugin);
+    app.add_plugins(materials::plugin);
     app.world_mut()
         .resource_mut::<GizmoConfigStore>()
         .config_mut::<PhysicsGizmos>()
@@ -0,0 +1,139 @@
+//! Player actions latched once per fixed timestep.
+//!
+//! Gameplay reads [`PlayerInput`] from the fixed schedules instead of the keyboard, so a run can
+//! be driven either by live input or by a recording and still play out the same way.
+
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game::game_system_set::AppSystems;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Reflect)]
+pub enum PlayerAction {
+    AimLeft,
+    AimRight,
+    PowerUp,
+    PowerDown,
+    TurnRateUp,
+    TurnRateDown,
+    TurnLeft,
+    TurnRight,
+    Throw,
+}
+
+impl PlayerAction {
+    pub const ALL: [Self; 9] = [
+        Self::AimLeft,
+        Self::AimRight,
+        Self::PowerUp,
+        Self::PowerDown,
+        Self::TurnRateUp,
+        Self::TurnRateDown,
+        Self::TurnLeft,
+        Self::TurnRight,
+        Self::Throw,
+    ];
+
+    pub fn key(self) -> KeyCode {
+        match self {
+            Self::AimLeft => KeyCode::ArrowLeft,
+            Self::AimRight => KeyCode::ArrowRight,
+            Self::PowerUp => KeyCode::ArrowUp,
+            Self::PowerDown => KeyCode::ArrowDown,
+            Self::TurnRateUp => KeyCode::KeyW,
+            Self::TurnRateDown => KeyCode::KeyS,
+            Self::TurnLeft => KeyCode::KeyA,
+            Self::TurnRight => KeyCode::KeyD,
+            Self::Throw => KeyCode::Space,
+        }
+    }
+
+    /// Held actions are active every tick their key is down, the rest once per key press.
+    fn is_held(self) -> bool {
+        !matches!(self, Self::Throw)
+    }
+
+    fn bit(self) -> u16 {
+        1 << self as u16
+    }
+}
+
+/// A set of [`PlayerAction`]s.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PlayerActions(u16);
+
+impl PlayerActions {
+    pub fn contains(&self, action: PlayerAction) -> bool {
+        self.0 & action.bit() != 0
+    }
+    pub fn insert(&mut self, action: PlayerAction) {
+        self.0 |= action.bit();
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+/// Actions for the current fixed tick.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Default, Copy, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct PlayerInput(pub PlayerActions);
+
+impl PlayerInput {
+    pub fn pressed(&self, action: PlayerAction) -> bool {
+        self.0.contains(action)
+    }
+}
+
+/// Keyboard state collected since the last fixed tick.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Default, Copy, Clone, Reflect)]
+#[reflect(Resource)]
+struct PendingPlayerInput {
+    held: PlayerActions,
+    /// Key presses are kept until a fixed tick consumes them, even across frames without one.
+    pressed: PlayerActions,
+}
+
+/// Systems in the `FixedPreUpdate` schedule that fill [`PlayerInput`] for the upcoming tick.
+#[derive(SystemSet, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct LatchPlayerInput;
+
+fn collect_keyboard_input(
+    input: Res<ButtonInput<KeyCode>>,
+    mut pending: ResMut<PendingPlayerInput>,
+) {
+    let mut held = PlayerActions::default();
+    for action in PlayerAction::ALL {
+        if action.is_held() {
+            if input.pressed(action.key()) {
+                held.insert(action);
+            }
+        } else if input.just_pressed(action.key()) {
+            pending.pressed.insert(action);
+        }
+    }
+    pending.held = held;
+}
+
+fn latch_player_input(
+    mut pending: ResMut<PendingPlayerInput>,
+    mut player_input: ResMut<PlayerInput>,
+) {
+    player_input.0 = PlayerActions(pending.held.0 | pending.pressed.0);
+    pending.pressed = PlayerActions::default();
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        collect_keyboard_input.in_set(AppSystems::RecordInput),
+    );
+    app.add_systems(FixedPreUpdate, latch_player_input.in_set(LatchPlayerInput));
+}
@@ -1,7 +1,9 @@
 use crate::game::asset_tracking::LoadResource;
-use avian3d::prelude::{Collider, Friction, Mass, Restitution};
+use crate::game::physics::layers::GameLayer;
+use crate::game::physics::materials::PhysicsMaterial;
+use crate::game::screens::Screen;
+use avian3d::prelude::{Collider, CollisionEventsEnabled, CollisionLayers};
 use avian3d::prelude::{ColliderDisabled, RigidBody};
-use bevy::ecs::query::QueryData;
 use bevy::prelude::*;
 use bevy_auto_plugin::auto_plugin::*;
 
@@ -14,6 +16,29 @@ use bevy_auto_plugin::auto_plugin::*;
 #[require(RigidBody::Dynamic)]
 pub struct BowlingBall;
 
+/// Marks a ball that was thrown by the player, as opposed to the one Zeus is holding.
+#[auto_register_type]
+#[derive(Component, Debug, Default, Copy, Clone, Reflect)]
+#[reflect(Component)]
+#[require(CollisionEventsEnabled)]
+pub struct ThrownBall;
+
+/// The kind of ball Zeus throws.
+#[auto_register_type]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Reflect)]
+pub enum BallType {
+    #[default]
+    Standard,
+}
+
+impl BallType {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Standard => "Standard",
+        }
+    }
+}
+
 pub const BOWLING_BALL_RADIUS: f32 = 0.108;
 
 #[auto_register_type]
@@ -36,21 +61,17 @@ impl FromWorld for BowlingBallAssets {
 
 #[auto_plugin(app=app)]
 pub(crate) fn plugin(app: &mut App) {
-    app.load_resource::<BowlingBallAssets>();
+    app.load_resource_scoped::<BowlingBallAssets>(
+        OnEnter(Screen::LoadLevel),
+        OnEnter(Screen::Title),
+    );
     app.add_observer(on_added);
 }
 
-#[derive(QueryData)]
-struct BowlingBallQueryData {
-    friction: Option<&'static Friction>,
-    restitution: Option<&'static Restitution>,
-    mass: Option<&'static Mass>,
-}
-
 fn on_added(
     trigger: Trigger<OnAdd, BowlingBall>,
     assets: Res<BowlingBallAssets>,
-    bowling_ball_q: Query<BowlingBallQueryData, With<BowlingBall>>,
+    bowling_ball_q: Query<Has<CollisionLayers>, With<BowlingBall>>,
     mut commands: Commands,
 ) {
     let entity = trigger.target();
@@ -59,15 +80,10 @@ fn on_added(
         Collider::sphere(BOWLING_BALL_RADIUS),
         SceneRoot(assets.bowling_ball.clone()),
     ));
-    let bb = bowling_ball_q.get(entity).expect("impossible");
-
-    if bb.friction.is_none() {
-        entity_cmds.insert(Friction::new(0.4));
-    }
-    if bb.restitution.is_none() {
-        entity_cmds.insert(Restitution::new(0.001));
-    }
-    if bb.mass.is_none() {
-        entity_cmds.insert(Mass(5.0));
+    // Balls spawned with their own material or mass keep it.
+    entity_cmds.insert_if_new(PhysicsMaterial::Ball);
+    let has_collision_layers = bowling_ball_q.get(entity).expect("impossible");
+    if !has_collision_layers {
+        entity_cmds.insert(GameLayer::Ball.collision_layers());
     }
 }
@@ -3,7 +3,10 @@ use avian3d::prelude::{CollisionEventsEnabled, Gravity};
 use crate::game::asset_tracking::LoadResource;
 use crate::game::audio::sound_effect;
 use crate::game::behaviors::MovementSpeed;
-use crate::game::rng::global::GlobalRng;
+use crate::game::physics::layers::GameLayer;
+use crate::game::rng::streams::{RngStream, RngStreams};
+use crate::game::scenes::LevelData;
+use crate::game::screens::Screen;
 use avian3d::prelude::{CenterOfMass, Collider, RigidBody};
 use bevy::prelude::*;
 use bevy_auto_plugin::auto_plugin::*;
@@ -57,6 +60,9 @@ pub enum Enemy {
 #[derive(Event, Debug, Default, Copy, Clone, Reflect)]
 pub struct PlayBoneSnap;
 
+/// Offset of the model relative to the enemy's collider.
+pub const ENEMY_MODEL_OFFSET: Vec3 = Vec3::new(0.0, -1.75, 0.0);
+
 const DEFAULT_MOVE_SPEED: f32 = 30.0;
 const DEFAULT_DESPAWN_AFTER_DEAD_SECS: f32 = 5.0;
 
@@ -71,6 +77,16 @@ impl Enemy {
             Self::BaseSkele => DEFAULT_DESPAWN_AFTER_DEAD_SECS,
         }
     }
+    pub fn scene(&self, enemy_assets: &EnemyAssets, gltfs: &Assets<Gltf>) -> Handle<Scene> {
+        // Model handle
+        let gltf_h = match *self {
+            Self::BaseSkele => &enemy_assets.base_skele,
+        };
+        let gltf = gltfs
+            .get(gltf_h)
+            .unwrap_or_else(|| panic!("Missing gltf asset for {:?}", self));
+        gltf.scenes[0].clone()
+    }
 }
 
 fn on_enemy_added(
@@ -80,31 +96,27 @@ fn on_enemy_added(
     gltfs: Res<Assets<Gltf>>,
     mut commands: Commands,
     gravity: Res<Gravity>,
+    level_data: Res<LevelData>,
 ) {
     let enemy = query
         .get(trigger.target())
         .expect("No target entity for trigger");
 
-    // Model handle
-    let gltf_h = match *enemy {
-        Enemy::BaseSkele => enemy_assets.base_skele.clone(),
-    };
-    let gltf = gltfs
-        .get(&gltf_h)
-        .unwrap_or_else(|| panic!("Missing gltf asset for {:?}", enemy));
-
     // MovementSpeed
-    let movement_speed = MovementSpeed(enemy.default_move_speed());
+    let movement_speed = MovementSpeed(
+        enemy.default_move_speed() * level_data.difficulty.enemy_speed_scale(level_data.wave),
+    );
 
     commands.entity(trigger.target()).insert((
         children![(
-            SceneRoot(gltf.scenes[0].clone()),
-            Transform::from_translation(Vec3::Y * -1.75),
+            SceneRoot(enemy.scene(&enemy_assets, &gltfs)),
+            Transform::from_translation(ENEMY_MODEL_OFFSET),
         ),],
         // Parry colliders are centered around origin. Meshes have lowest
         // vertex at y=0.0. Spawning the collider allows us to adjust
         // its position to match the mesh.
         Collider::capsule(0.25, 3.0),
+        GameLayer::Enemy.collision_layers(),
         CenterOfMass::new(0.0, -5.5, 0.0),
         RigidBody::Dynamic,
         movement_speed,
@@ -113,7 +125,7 @@ fn on_enemy_added(
 
 fn play_bone_snap(
     _trigger: Trigger<PlayBoneSnap>,
-    mut global_rng: GlobalRng,
+    mut rng_streams: RngStreams,
     mut commands: Commands,
     enemy_assets: Res<EnemyAssets>,
 ) {
@@ -121,7 +133,7 @@ fn play_bone_snap(
     commands.spawn(sound_effect(
         enemy_assets
             .bone_snap_sounds
-            .choose(global_rng.rng())
+            .choose(&mut *rng_streams.rng(RngStream::Cosmetic))
             .unwrap()
             .clone(),
     ));
@@ -129,7 +141,7 @@ fn play_bone_snap(
 
 #[auto_plugin(app=app)]
 pub(crate) fn plugin(app: &mut App) {
-    app.load_resource::<EnemyAssets>();
+    app.load_resource_scoped::<EnemyAssets>(OnEnter(Screen::LoadLevel), OnEnter(Screen::Title));
     app.add_observer(on_enemy_added);
     app.add_observer(play_bone_snap);
 }
@@ -1,4 +1,5 @@
-use crate::game::asset_tracking::LoadResource;
+use crate::game::levels::SelectedLevel;
+use crate::game::screens::Screen;
 use avian3d::prelude::RigidBody;
 use bevy::prelude::*;
 use bevy_auto_plugin::auto_plugin::*;
@@ -12,27 +13,39 @@ use bevy_auto_plugin::auto_plugin::*;
 #[require(RigidBody::Static)]
 pub struct GameWorld;
 
+/// The world of the selected level. Loaded when the level starts loading rather than at startup.
 #[auto_register_type]
-#[derive(Resource, Asset, Debug, Clone, Reflect)]
+#[derive(Resource, Debug, Clone, Reflect)]
 #[reflect(Resource)]
 pub struct GameWorldAssets {
-    #[dependency]
     pub scene: Handle<Scene>,
 }
 
-impl FromWorld for GameWorldAssets {
-    fn from_world(world: &mut World) -> Self {
-        let assets = world.resource::<AssetServer>();
-        Self {
-            scene: assets.load(GltfAssetLabel::Scene(0).from_asset("models/world/world.glb")),
-        }
+/// Starts loading the selected level's world, unless it's the one already loaded.
+pub fn load_game_world_assets(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    selected_level: Res<SelectedLevel>,
+    current: Option<Res<GameWorldAssets>>,
+) {
+    let path = GltfAssetLabel::Scene(0).from_asset(selected_level.level().scene);
+    if current.is_some_and(|current| current.scene.path() == Some(&path)) {
+        return;
     }
+    commands.insert_resource(GameWorldAssets {
+        scene: assets.load(path),
+    });
+}
+
+/// Releases the world once the player is back on the title screen.
+fn unload_game_world_assets(mut commands: Commands) {
+    commands.remove_resource::<GameWorldAssets>();
 }
 
 #[auto_plugin(app=app)]
 pub(crate) fn plugin(app: &mut App) {
-    app.load_resource::<GameWorldAssets>();
     app.add_observer(on_added);
+    app.add_systems(OnEnter(Screen::Title), unload_game_world_assets);
 }
 
 fn on_added(
@@ -0,0 +1,130 @@
+//! Picking where formations enter from among the [`EnemySpawnMarker`]s of the level.
+
+use std::f32::consts::PI;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+use crate::game::prefabs::game_world_markers::EnemySpawnMarker;
+use crate::game::rng::annulus::sample_point_in_annulus;
+use crate::game::rng::disc::sample_point_in_disc;
+use crate::game::rng::mesh::{TriangleSampler, transformed_triangles};
+use crate::game::rng::sphere::sample_point_in_sphere;
+
+/// Area around an [`EnemySpawnMarker`] formations may enter from, in the marker's local space.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Reflect)]
+pub enum SpawnRegion {
+    /// Exactly at the marker.
+    #[default]
+    Point,
+    /// Anywhere within `radius` on the marker's ground plane.
+    Disc { radius: f32 },
+    /// Between `inner_radius` and `outer_radius` on the ground plane, e.g. a ring around an arena.
+    Annulus {
+        inner_radius: f32,
+        outer_radius: f32,
+    },
+    /// Anywhere in the upper half of a ball of `radius`, for formations that drop in.
+    Dome { radius: f32 },
+    /// Anywhere on the meshes of the marker and its descendants.
+    Mesh,
+}
+
+#[derive(SystemParam)]
+pub struct EnemySpawns<'w, 's> {
+    markers: Query<'w, 's, (Entity, &'static EnemySpawnMarker)>,
+    children: Query<'w, 's, &'static Children>,
+    mesh3ds: Query<'w, 's, &'static Mesh3d>,
+    meshes: Res<'w, Assets<Mesh>>,
+    transform_helper: TransformHelper<'w, 's>,
+}
+
+impl EnemySpawns<'_, '_> {
+    /// Markers with their world transform, in a stable order.
+    fn markers(&self) -> Vec<(Entity, &EnemySpawnMarker, GlobalTransform)> {
+        let mut markers = self
+            .markers
+            .iter()
+            .filter_map(|(entity, marker)| {
+                let global_transform = self.transform_helper.compute_global_transform(entity);
+                global_transform.ok().map(|gt| (entity, marker, gt))
+            })
+            .collect::<Vec<_>>();
+        // Entity ids differ between runs, positions don't. Keeps seeded runs identical.
+        markers.sort_by(|(_, _, a), (_, _, b)| {
+            a.translation()
+                .to_array()
+                .partial_cmp(&b.translation().to_array())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        markers
+    }
+
+    /// World transform of the first marker, without any scatter.
+    pub fn center(&self) -> Option<Transform> {
+        self.markers()
+            .first()
+            .map(|(_, _, gt)| gt.compute_transform())
+    }
+
+    /// Picks a random marker, then a random point and heading in its region. World space.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Transform> {
+        let markers = self.markers();
+        let (entity, marker, global_transform) = markers.choose(rng)?;
+        let mut transform = global_transform.compute_transform();
+        let offset = match marker.region {
+            SpawnRegion::Point => Vec3::ZERO,
+            SpawnRegion::Disc { radius } => sample_point_in_disc(rng).extend(0.0).xzy() * radius,
+            SpawnRegion::Annulus {
+                inner_radius,
+                outer_radius,
+            } => sample_point_in_annulus(rng, inner_radius, outer_radius)
+                .extend(0.0)
+                .xzy(),
+            SpawnRegion::Dome { radius } => {
+                let point = sample_point_in_sphere(rng) * radius;
+                point.with_y(point.y.abs())
+            }
+            SpawnRegion::Mesh => match self.mesh_sampler(*entity) {
+                Some(sampler) => {
+                    // Already in world space.
+                    transform.translation = sampler.sample(rng);
+                    Vec3::ZERO
+                }
+                None => {
+                    warn!("EnemySpawnMarker {entity} uses SpawnRegion::Mesh but has no meshes");
+                    Vec3::ZERO
+                }
+            },
+        };
+        // Scaling the marker in Blender scales its region along with it.
+        transform.translation += transform.rotation * (transform.scale * offset);
+        let jitter = marker.heading_jitter_degrees.to_radians().clamp(0.0, PI);
+        if jitter > 0.0 {
+            transform.rotate_y(rng.random_range(-jitter..=jitter));
+        }
+        Some(transform)
+    }
+
+    fn mesh_sampler(&self, marker: Entity) -> Option<TriangleSampler> {
+        let mut triangles = Vec::new();
+        for entity in std::iter::once(marker).chain(self.children.iter_descendants(marker)) {
+            let Some(mesh) = self
+                .mesh3ds
+                .get(entity)
+                .ok()
+                .and_then(|mesh3d| self.meshes.get(&mesh3d.0))
+            else {
+                continue;
+            };
+            let Ok(global_transform) = self.transform_helper.compute_global_transform(entity)
+            else {
+                continue;
+            };
+            triangles.extend(transformed_triangles(mesh, &global_transform));
+        }
+        TriangleSampler::new(triangles)
+    }
+}
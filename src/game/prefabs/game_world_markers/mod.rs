@@ -1,7 +1,14 @@
+use crate::game::physics::collider_cache::{
+    BakedCollider, ColliderCache, PendingColliderBake, collider_key,
+};
+use crate::game::physics::layers::GameLayer;
+use crate::game::physics::materials::PhysicsMaterial;
 use crate::game::prefabs::game_world::GameWorld;
+use crate::game::prefabs::game_world_markers::enemy_spawns::{EnemySpawns, SpawnRegion};
 use avian3d::prelude::{
-    AngularInertia, CenterOfMass, Collider, ColliderConstructor, ColliderConstructorHierarchy,
-    Mass, NoAutoAngularInertia, NoAutoCenterOfMass, NoAutoMass, RigidBody, VhacdParameters,
+    AngularInertia, CenterOfMass, Collider, ColliderConstructor, Friction, Mass,
+    NoAutoAngularInertia, NoAutoCenterOfMass, NoAutoMass, Restitution, RigidBody, Sensor,
+    VhacdParameters,
 };
 use bevy::ecs::query::QueryData;
 use bevy::ecs::system::SystemParam;
@@ -10,12 +17,25 @@ use bevy::scene::SceneInstanceReady;
 use bevy_auto_plugin::auto_plugin::*;
 use smart_default::SmartDefault;
 
+pub mod enemy_spawns;
+
 #[auto_register_type]
 #[auto_name]
-#[derive(Component, Debug, Default, Copy, Clone, Reflect)]
+#[derive(Component, Debug, SmartDefault, Copy, Clone, Reflect)]
 #[reflect(Component)]
 #[require(Transform)]
-pub struct EnemySpawnMarker;
+pub struct EnemySpawnMarker {
+    #[reflect(default)]
+    pub region: SpawnRegion,
+    /// Formations turn up to this many degrees away from the marker's heading.
+    #[default(15.0)]
+    #[reflect(default = "default_heading_jitter_degrees")]
+    pub heading_jitter_degrees: f32,
+}
+
+fn default_heading_jitter_degrees() -> f32 {
+    EnemySpawnMarker::default().heading_jitter_degrees
+}
 
 #[auto_register_type]
 #[auto_name]
@@ -38,25 +58,31 @@ pub struct BowlingBallSpawnMarker;
 #[require(Transform)]
 pub struct OutOfBoundsMarker;
 
+/// Any part of the temple, its colliders go on [`GameLayer::Temple`].
+#[auto_register_type]
+#[derive(Component, Debug, Default, Copy, Clone, Reflect)]
+#[reflect(Component)]
+pub struct TemplePiece;
+
 #[auto_register_type]
 #[auto_name]
 #[derive(Component, Debug, Default, Copy, Clone, Reflect)]
 #[reflect(Component)]
-#[require(Transform)]
+#[require(Transform, TemplePiece)]
 pub struct TemplePillar;
 
 #[auto_register_type]
 #[auto_name]
 #[derive(Component, Debug, Default, Copy, Clone, Reflect)]
 #[reflect(Component)]
-#[require(Transform)]
+#[require(Transform, TemplePiece)]
 pub struct TempleBase;
 
 #[auto_register_type]
 #[auto_name]
 #[derive(Component, Debug, Default, Copy, Clone, Reflect)]
 #[reflect(Component)]
-#[require(Transform)]
+#[require(Transform, TemplePiece)]
 pub struct TempleRoof;
 
 #[auto_register_type]
@@ -71,145 +97,234 @@ pub struct TempleLight;
 #[reflect(Component)]
 struct ColliderDisabled;
 
+/// How [`AutoColliderMesh`] turns a mesh into a collider.
 #[auto_register_type]
-#[derive(Debug, Default, Copy, Clone, Reflect)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Reflect)]
 pub enum Method {
     #[default]
     ConvexHull,
     ConvexDecomposition,
+    /// [`Method::ConvexDecomposition`] without approximating the hulls, regardless of
+    /// [`AutoColliderMesh::vhacd`].
     ConvexDecompositionNoApprox,
     TriMesh,
 }
 
+/// Where [`AutoColliderMesh`] puts the rigid body.
+#[auto_register_type]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Reflect)]
+pub enum ColliderMode {
+    /// Every mesh below the marker becomes its own rigid body.
+    #[default]
+    PerMesh,
+    /// The marker becomes one rigid body, the meshes below it its colliders.
+    Hierarchy,
+}
+
+/// V-HACD parameters for [`Method::ConvexDecomposition`], mirroring [`VhacdParameters`].
+#[auto_register_type]
+#[derive(Debug, SmartDefault, Copy, Clone, PartialEq, Reflect)]
+#[reflect(Default)]
+pub struct VhacdConfig {
+    #[default(0.01)]
+    pub concavity: f32,
+    #[default(0.05)]
+    pub alpha: f32,
+    #[default(0.05)]
+    pub beta: f32,
+    #[default(64)]
+    pub resolution: u32,
+    #[default(4)]
+    pub plane_downsampling: u32,
+    #[default(4)]
+    pub convex_hull_downsampling: u32,
+    #[default(true)]
+    pub convex_hull_approximation: bool,
+    #[default(1024)]
+    pub max_convex_hulls: u32,
+}
+
+impl VhacdConfig {
+    pub fn parameters(&self) -> VhacdParameters {
+        VhacdParameters {
+            concavity: self.concavity,
+            alpha: self.alpha,
+            beta: self.beta,
+            resolution: self.resolution,
+            plane_downsampling: self.plane_downsampling,
+            convex_hull_downsampling: self.convex_hull_downsampling,
+            convex_hull_approximation: self.convex_hull_approximation,
+            max_convex_hulls: self.max_convex_hulls,
+            ..default()
+        }
+    }
+}
+
+/// Generates colliders for the meshes at and below this entity once the world scene is spawned.
+///
+/// Every field but `method` is optional in Blender, unset ones keep avian's defaults.
 #[auto_register_type]
 #[derive(Component, Debug, SmartDefault, Copy, Clone, Reflect)]
 #[reflect(Component)]
 #[require(Transform)]
 pub struct AutoColliderMesh {
-    method: Method,
+    pub method: Method,
+    #[reflect(default)]
+    pub mode: ColliderMode,
+    /// Overrides the V-HACD parameters of the convex decomposition methods.
+    #[reflect(default)]
+    pub vhacd: Option<VhacdConfig>,
+    /// Defaults to [`GameLayer::Temple`] on temple pieces, [`GameLayer::Trigger`] for sensors and
+    /// [`GameLayer::World`] otherwise.
+    #[reflect(default)]
+    pub layer: Option<GameLayer>,
+    /// Overrides the friction of the entity's [`PhysicsMaterial`].
+    #[reflect(default)]
+    pub friction: Option<f32>,
+    /// Overrides the restitution of the entity's [`PhysicsMaterial`].
+    #[reflect(default)]
+    pub restitution: Option<f32>,
+    /// Only detect overlaps instead of colliding.
+    #[reflect(default)]
+    pub sensor: bool,
+}
+
+impl AutoColliderMesh {
+    pub fn constructor(&self) -> ColliderConstructor {
+        let vhacd = self.vhacd.unwrap_or_default();
+        match self.method {
+            Method::ConvexHull => ColliderConstructor::ConvexHullFromMesh,
+            Method::ConvexDecomposition if self.vhacd.is_none() => {
+                ColliderConstructor::ConvexDecompositionFromMesh
+            }
+            Method::ConvexDecomposition => {
+                ColliderConstructor::ConvexDecompositionFromMeshWithConfig(vhacd.parameters())
+            }
+            Method::ConvexDecompositionNoApprox => {
+                ColliderConstructor::ConvexDecompositionFromMeshWithConfig(VhacdParameters {
+                    convex_hull_approximation: false,
+                    ..vhacd.parameters()
+                })
+            }
+            Method::TriMesh => ColliderConstructor::TrimeshFromMesh,
+        }
+    }
+
+    /// Everything but the shape itself, for each generated collider.
+    fn collider_properties(
+        &self,
+        entity_cmds: &mut EntityCommands,
+        material: Option<PhysicsMaterial>,
+        is_temple: bool,
+    ) {
+        let layer = self.layer.unwrap_or(if is_temple {
+            GameLayer::Temple
+        } else if self.sensor {
+            GameLayer::Trigger
+        } else {
+            GameLayer::World
+        });
+        entity_cmds.insert(layer.collision_layers());
+        if let Some(material) = material {
+            entity_cmds.insert(material);
+        }
+        if let Some(friction) = self.friction {
+            entity_cmds.insert(Friction::new(friction));
+        }
+        if let Some(restitution) = self.restitution {
+            entity_cmds.insert(Restitution::new(restitution));
+        }
+        if self.sensor {
+            entity_cmds.insert(Sensor);
+        }
+    }
+}
+
+fn insert_rigid_body(entity_cmds: &mut EntityCommands, rigid_body: RigidBody) {
+    if matches!(rigid_body, RigidBody::Static) {
+        // required for large meshes to prevent: assertion failed: self.is_normalized()
+        //  avian3d::dynamics::rigid_body::mass_properties::update_mass_properties
+        entity_cmds.insert((
+            NoAutoMass,
+            NoAutoAngularInertia,
+            NoAutoCenterOfMass,
+            Mass::ZERO,
+            AngularInertia::ZERO,
+            CenterOfMass::ZERO,
+        ));
+    }
+    entity_cmds.insert(rigid_body);
 }
 
 pub fn auto_collider_mesh_obs(
     trigger: Trigger<SceneInstanceReady>,
     mut commands: Commands,
     auto_collider_mesh_q: Query<
-        (Entity, Ref<AutoColliderMesh>, Option<&RigidBody>),
+        (
+            Entity,
+            &AutoColliderMesh,
+            Option<&RigidBody>,
+            Option<&PhysicsMaterial>,
+            Has<TemplePiece>,
+        ),
         Added<AutoColliderMesh>,
     >,
-    added_mesh3d_q: Query<(Ref<Mesh3d>, Has<Collider>), Added<Mesh3d>>,
+    mesh3d_q: Query<(&Mesh3d, Has<Collider>)>,
     children_q: Query<&Children>,
+    meshes: Res<Assets<Mesh>>,
+    mut collider_cache: ResMut<ColliderCache>,
 ) {
     commands.entity(trigger.observer()).despawn();
     let entity = trigger.target();
     info!("Trigger<SceneInstanceReady> {entity}");
     for child in children_q.iter_descendants(entity) {
-        let Ok((entity, auto_collider_mesh_ref, rigid_body_opt)) = auto_collider_mesh_q.get(child)
+        let Ok((entity, auto_collider_mesh, rigid_body_opt, material, is_temple)) =
+            auto_collider_mesh_q.get(child)
         else {
             continue;
         };
-        if !auto_collider_mesh_ref.is_added() {
-            continue;
+        let rigid_body = rigid_body_opt.copied().unwrap_or(RigidBody::Static);
+        // A rigid body that's already there owns every mesh below it.
+        let hierarchy =
+            auto_collider_mesh.mode == ColliderMode::Hierarchy || rigid_body_opt.is_some();
+        if hierarchy {
+            insert_rigid_body(&mut commands.entity(entity), rigid_body);
         }
+        let constructor = auto_collider_mesh.constructor();
         let queue = vec![entity]
             .into_iter()
             .chain(children_q.iter_descendants(entity));
-        for entity in queue {
-            let Ok((mesh3d_ref, has_collider)) = added_mesh3d_q.get(entity) else {
+        for mesh_entity in queue {
+            let Ok((mesh3d, has_collider)) = mesh3d_q.get(mesh_entity) else {
                 continue;
             };
-            if !mesh3d_ref.is_added() || has_collider {
+            if has_collider {
                 continue;
             }
-            info!("ConvexHullFromMesh {entity}");
-            let mut entity_cmds = commands.entity(entity);
-            let has_rigid_body = rigid_body_opt.is_some();
-            let rigid_body = rigid_body_opt.copied().unwrap_or(RigidBody::Static);
-            if matches!(rigid_body, RigidBody::Static) {
-                // required for large meshes to prevent: assertion failed: self.is_normalized()
-                //  avian3d::dynamics::rigid_body::mass_properties::update_mass_properties
-                entity_cmds.insert((
-                    NoAutoMass,
-                    NoAutoAngularInertia,
-                    NoAutoCenterOfMass,
-                    Mass::ZERO,
-                    AngularInertia::ZERO,
-                    CenterOfMass::ZERO,
-                ));
+            info!("{:?} {mesh_entity}", auto_collider_mesh.method);
+            let mut entity_cmds = commands.entity(mesh_entity);
+            if !hierarchy {
+                insert_rigid_body(&mut entity_cmds, rigid_body);
             }
-            if !has_rigid_body {
-                entity_cmds.insert(rigid_body);
+            auto_collider_mesh.collider_properties(&mut entity_cmds, material.copied(), is_temple);
+            let key = meshes
+                .get(&mesh3d.0)
+                .and_then(|mesh| collider_key(mesh, &constructor));
+            if let Some(collider) = key
+                .and_then(|key| collider_cache.get(key))
+                .and_then(BakedCollider::to_collider)
+            {
+                entity_cmds.insert(collider);
+                continue;
             }
-            match auto_collider_mesh_ref.method {
-                Method::ConvexHull => {
-                    entity_cmds.insert(ColliderConstructor::ConvexHullFromMesh);
-                }
-                Method::ConvexDecomposition => {
-                    entity_cmds.insert(ColliderConstructor::ConvexDecompositionFromMesh);
-                }
-                Method::ConvexDecompositionNoApprox => {
-                    entity_cmds.insert(ColliderConstructor::ConvexDecompositionFromMeshWithConfig(
-                        VhacdParameters {
-                            convex_hull_approximation: false,
-                            ..Default::default()
-                        },
-                    ));
-                }
-                Method::TriMesh => {
-                    entity_cmds.insert(ColliderConstructor::TrimeshFromMesh);
-                }
+            if let Some(key) = key {
+                entity_cmds.insert(PendingColliderBake(key));
             }
+            entity_cmds.insert(constructor.clone());
         }
     }
 }
 
-#[auto_register_type]
-#[derive(Component, Debug, SmartDefault, Copy, Clone, Reflect)]
-#[reflect(Component)]
-#[require(Transform)]
-pub struct AutoColliderMesh2 {
-    method: Method,
-}
-
-pub fn auto_collider_mesh2(
-    mut commands: Commands,
-    auto_collider_mesh_q: Query<
-        (Entity, &AutoColliderMesh2, Option<&RigidBody>),
-        Added<AutoColliderMesh2>,
-    >,
-) {
-    for (entity, auto_collider_mesh_ref, rigid_body_opt) in auto_collider_mesh_q.iter() {
-        info!("AutoColliderMesh2 {entity}");
-        let mut entity_cmds = commands.entity(entity);
-        let rigid_body = rigid_body_opt.copied().unwrap_or(RigidBody::Static);
-        if matches!(rigid_body, RigidBody::Static) {
-            // required for large meshes to prevent: assertion failed: self.is_normalized()
-            //  avian3d::dynamics::rigid_body::mass_properties::update_mass_properties
-            entity_cmds.insert((
-                NoAutoMass,
-                NoAutoAngularInertia,
-                NoAutoCenterOfMass,
-                Mass::ZERO,
-                AngularInertia::ZERO,
-                CenterOfMass::ZERO,
-            ));
-        }
-        entity_cmds.insert(rigid_body);
-        entity_cmds.insert(ColliderConstructorHierarchy::new(
-            match auto_collider_mesh_ref.method {
-                Method::ConvexHull => ColliderConstructor::ConvexHullFromMesh,
-                Method::ConvexDecomposition => ColliderConstructor::ConvexDecompositionFromMesh,
-                Method::ConvexDecompositionNoApprox => {
-                    ColliderConstructor::ConvexDecompositionFromMeshWithConfig(VhacdParameters {
-                        convex_hull_approximation: false,
-                        ..Default::default()
-                    })
-                }
-                Method::TriMesh => ColliderConstructor::TrimeshFromMesh,
-            },
-        ));
-    }
-}
-
 // TODO: move
 pub trait ComponentName {
     fn component_name() -> &'static str;
@@ -327,7 +442,7 @@ where
 #[derive(SystemParam)]
 pub struct GameWorldMarkerSystemParam<'w, 's> {
     pub player_spawn: SpawnHelper<'w, 's, GameWorld, PlayerSpawnMarker>,
-    pub enemy_spawn: SpawnHelper<'w, 's, GameWorld, EnemySpawnMarker>,
+    pub enemy_spawns: EnemySpawns<'w, 's>,
 }
 
 impl GameWorldMarkerSystemParam<'_, '_> {
@@ -339,12 +454,18 @@ impl GameWorldMarkerSystemParam<'_, '_> {
         self.player_spawn.spawn_in(bundle, transform)
     }
 
-    pub fn spawn_in_enemy_spawn(
-        &mut self,
-        bundle: impl Bundle,
-        transform: Option<Transform>,
-    ) -> Entity {
-        self.enemy_spawn.spawn_in(bundle, transform)
+    /// Spawns `bundle` in the [`GameWorld`] at the world space `transform`.
+    pub fn spawn_in_world(&mut self, bundle: impl Bundle, transform: Transform) -> Entity {
+        let parent_global_transform = self.player_spawn.parent_get_or_compute_global_transform();
+        let local_transform = GlobalTransform::from(transform)
+            .reparented_to(&parent_global_transform)
+            .with_scale(transform.scale);
+        let commands = &mut self.player_spawn.commands;
+        let child = commands.spawn(bundle).insert(local_transform).id();
+        commands
+            .entity(self.player_spawn.parent_q.entity)
+            .add_child(child);
+        child
     }
 }
 
@@ -359,5 +480,4 @@ fn on_add_collider_disabled(trigger: Trigger<OnAdd, ColliderDisabled>, mut comma
 pub(crate) fn plugin(app: &mut App) {
     app.add_observer(on_add_collider_disabled);
     app.add_observer(auto_collider_mesh_obs);
-    // app.add_systems(Update, auto_collider_mesh2);
 }
@@ -3,6 +3,8 @@ pub mod enemy;
 pub mod game_world;
 pub mod game_world_markers;
 pub mod player;
+pub mod player_throw;
+pub mod ragdoll;
 pub mod spawner;
 
 use bevy::prelude::*;
@@ -14,6 +16,8 @@ pub(crate) fn plugin(app: &mut App) {
     app.add_plugins(spawner::plugin);
     app.add_plugins(bowling_ball::plugin);
     app.add_plugins(player::plugin);
+    app.add_plugins(player_throw::plugin);
+    app.add_plugins(ragdoll::plugin);
     app.add_plugins(game_world::plugin);
     app.add_plugins(game_world_markers::plugin);
 }
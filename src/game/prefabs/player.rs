@@ -4,12 +4,15 @@ use crate::game::asset_tracking::LoadResource;
 use crate::game::audio::sound_effect;
 use crate::game::behaviors::despawn::Despawn;
 use crate::game::camera::CameraTarget;
-use crate::game::prefabs::bowling_ball::BowlingBall;
+use crate::game::physics::layers::GameLayer;
+use crate::game::prefabs::bowling_ball::{BowlingBall, ThrownBall};
 use crate::game::prefabs::game_world::GameWorld;
 use crate::game::prefabs::game_world_markers::{
     BowlingBallSpawnMarker, ComponentName, SpawnHelper,
 };
-use crate::game::rng::global::GlobalRng;
+use crate::game::prefabs::player_throw::ReleaseBall;
+use crate::game::rng::streams::{RngStream, RngStreams};
+use crate::game::screens::Screen;
 use avian3d::prelude::{Collider, ExternalAngularImpulse, ExternalImpulse, Mass, RigidBody};
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
@@ -66,7 +69,7 @@ pub struct PlayerSystemParam<'w, 's> {
     player: SpawnHelper<'w, 's, GameWorld, Player>,
     player_assets: Res<'w, PlayerAssets>,
     pub bowling_ball_spawn: SpawnHelper<'w, 's, GameWorld, BowlingBallSpawnMarker>,
-    rng: GlobalRng<'w, 's>,
+    rng_streams: RngStreams<'w, 's>,
 }
 
 impl PlayerSystemParam<'_, '_> {
@@ -83,19 +86,36 @@ impl PlayerSystemParam<'_, '_> {
     ) -> Entity {
         self.bowling_ball_spawn.spawn_in(bundle, transform)
     }
+    /// Has Zeus throw, see [`ReleaseBall`].
+    pub fn throw_ball(&mut self, power: f32, accuracy_offset_radians: f32) {
+        let entity = self.entity();
+        self.commands.trigger_targets(
+            ReleaseBall {
+                power,
+                accuracy_offset_radians,
+            },
+            entity,
+        );
+    }
     pub fn spawn_bowling_ball(&mut self, power: f32, accuracy_offset_radians: f32) -> Entity {
         let player_rot = self.get_player_rotation();
         let accuracy_rot = player_rot * Quat::from_rotation_y(accuracy_offset_radians);
-        let rng = self.rng.rng();
+        let mut rng = self.rng_streams.rng(RngStream::Cosmetic);
         self.commands.spawn(sound_effect(
-            self.player_assets.throw_sounds.choose(rng).unwrap().clone(),
+            self.player_assets
+                .throw_sounds
+                .choose(&mut *rng)
+                .unwrap()
+                .clone(),
         ));
         let bowling_ball = self.spawn_bowling_ball_spawn(
             (
                 BowlingBall,
+                ThrownBall,
                 CameraTarget,
                 ExternalAngularImpulse::new(accuracy_rot * (Vec3::X * 10.0 * power)),
                 ExternalImpulse::new(accuracy_rot * (Vec3::Z * 1000.0 * power)),
+                // Thrown balls are scaled up, so they weigh more than the ball preset.
                 Mass(20.0),
                 Despawn {
                     ttl: Duration::from_secs_f32(10.0),
@@ -109,13 +129,15 @@ impl PlayerSystemParam<'_, '_> {
 
 #[auto_plugin(app=app)]
 pub(crate) fn plugin(app: &mut App) {
-    app.load_resource::<PlayerAssets>();
+    app.load_resource_scoped::<PlayerAssets>(OnEnter(Screen::LoadLevel), OnEnter(Screen::Title));
     app.add_observer(on_added);
 }
 
 fn on_added(trigger: Trigger<OnAdd, Player>, assets: Res<PlayerAssets>, mut commands: Commands) {
     let entity = trigger.target();
-    commands
-        .entity(entity)
-        .insert((SceneRoot(assets.scene.clone()), Collider::capsule(3.0, 8.0)));
+    commands.entity(entity).insert((
+        SceneRoot(assets.scene.clone()),
+        Collider::capsule(3.0, 8.0),
+        GameLayer::Player.collision_layers(),
+    ));
 }
@@ -0,0 +1,27 @@
+//! Zeus's throw.
+//!
+//! His rig has no throw animation yet, so the ball leaves his hand on the same tick the throw input
+//! lands. [`ReleaseBall`] is where a release frame event hooks in once there's a clip to sync to.
+
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::prefabs::player::PlayerSystemParam;
+
+/// Triggered on Zeus when the ball leaves his hand, spawns the ball.
+#[auto_register_type]
+#[derive(Event, Debug, Copy, Clone, Reflect)]
+pub struct ReleaseBall {
+    pub power: f32,
+    pub accuracy_offset_radians: f32,
+}
+
+fn release_ball(trigger: Trigger<ReleaseBall>, mut player_system_param: PlayerSystemParam) {
+    let event = trigger.event();
+    player_system_param.spawn_bowling_ball(event.power, event.accuracy_offset_radians);
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_observer(release_ball);
+}
@@ -0,0 +1,274 @@
+//! Turns knocked-down skeletons into ragdolls: the rig's deform bones get hung off a chain of
+//! capsules joined at the hips, knees, shoulders and so on.
+//!
+//! The enemy's own capsule keeps simulating underneath, so knockdowns and chain reactions play out
+//! the same with ragdolls on or off. The limbs only collide with the level.
+
+use std::collections::HashMap;
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, FRAC_PI_6};
+
+use avian3d::prelude::{AngularVelocity, Collider, LinearVelocity, RigidBody, SphericalJoint};
+use bevy::animation::AnimationTarget;
+use bevy::prelude::*;
+use bevy::render::mesh::skinning::SkinnedMesh;
+use bevy::render::view::NoFrustumCulling;
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::behaviors::despawn::Despawn;
+use crate::game::behaviors::knockdown::KnockedDown;
+use crate::game::physics::layers::GameLayer;
+use crate::game::prefabs::enemy::Enemy;
+
+/// Whether knocked-down skeletons turn into ragdolls or tip over whole like pins.
+///
+/// Off by default: every ragdoll adds a dozen bodies and joints to the simulation, which slower
+/// machines and the web build can't keep up with during big waves.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Default, Copy, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct RagdollSettings {
+    pub enabled: bool,
+}
+
+/// A rigid body standing in for part of a skeleton.
+#[auto_register_type]
+#[auto_name]
+#[derive(Component, Debug, Copy, Clone, Reflect)]
+#[reflect(Component)]
+pub struct RagdollPart {
+    pub enemy: Entity,
+}
+
+struct RagdollSegment {
+    /// Deform bones carried by this body, the first one sets its pose.
+    bones: &'static [&'static str],
+    /// Index of the segment this one hangs off.
+    parent: Option<usize>,
+    /// Capsule size along the first bone, in rig units.
+    length: f32,
+    radius: f32,
+    /// Furthest the joint swings or twists away from its rest pose.
+    limit: f32,
+}
+
+const SEGMENTS: [RagdollSegment; 13] = [
+    // 0: hips
+    RagdollSegment {
+        bones: &["DEF-spine", "DEF-spine.001"],
+        parent: None,
+        length: 0.49,
+        radius: 0.12,
+        limit: 0.0,
+    },
+    // 1: chest
+    RagdollSegment {
+        bones: &["DEF-spine.002", "DEF-spine.003"],
+        parent: Some(0),
+        length: 0.67,
+        radius: 0.15,
+        limit: FRAC_PI_6,
+    },
+    // 2: head
+    RagdollSegment {
+        bones: &["DEF-spine.004", "DEF-spine.005", "DEF-spine.006"],
+        parent: Some(1),
+        length: 0.4,
+        radius: 0.1,
+        limit: FRAC_PI_4,
+    },
+    // 3: left thigh
+    RagdollSegment {
+        bones: &["DEF-thigh.L", "DEF-thigh.L.001"],
+        parent: Some(0),
+        length: 0.68,
+        radius: 0.06,
+        limit: FRAC_PI_4,
+    },
+    // 4: left shin
+    RagdollSegment {
+        bones: &["DEF-shin.L", "DEF-shin.L.001"],
+        parent: Some(3),
+        length: 0.6,
+        radius: 0.05,
+        limit: FRAC_PI_4,
+    },
+    // 5: left foot
+    RagdollSegment {
+        bones: &["DEF-foot.L", "DEF-toe.L"],
+        parent: Some(4),
+        length: 0.25,
+        radius: 0.04,
+        limit: FRAC_PI_6,
+    },
+    // 6: right thigh
+    RagdollSegment {
+        bones: &["DEF-thigh.R", "DEF-thigh.R.001"],
+        parent: Some(0),
+        length: 0.68,
+        radius: 0.06,
+        limit: FRAC_PI_4,
+    },
+    // 7: right shin
+    RagdollSegment {
+        bones: &["DEF-shin.R", "DEF-shin.R.001"],
+        parent: Some(6),
+        length: 0.6,
+        radius: 0.05,
+        limit: FRAC_PI_4,
+    },
+    // 8: right foot
+    RagdollSegment {
+        bones: &["DEF-foot.R", "DEF-toe.R"],
+        parent: Some(7),
+        length: 0.25,
+        radius: 0.04,
+        limit: FRAC_PI_6,
+    },
+    // 9: left upper arm
+    RagdollSegment {
+        bones: &["DEF-upper_arm.L", "DEF-upper_arm.L.001"],
+        parent: Some(1),
+        length: 0.52,
+        radius: 0.05,
+        limit: FRAC_PI_2,
+    },
+    // 10: left forearm
+    RagdollSegment {
+        bones: &["DEF-forearm.L", "DEF-forearm.L.001", "DEF-hand.L"],
+        parent: Some(9),
+        length: 0.53,
+        radius: 0.04,
+        limit: FRAC_PI_4,
+    },
+    // 11: right upper arm
+    RagdollSegment {
+        bones: &["DEF-upper_arm.R", "DEF-upper_arm.R.001"],
+        parent: Some(1),
+        length: 0.52,
+        radius: 0.05,
+        limit: FRAC_PI_2,
+    },
+    // 12: right forearm
+    RagdollSegment {
+        bones: &["DEF-forearm.R", "DEF-forearm.R.001", "DEF-hand.R"],
+        parent: Some(11),
+        length: 0.53,
+        radius: 0.04,
+        limit: FRAC_PI_4,
+    },
+];
+
+fn ragdoll_on_knockdown(
+    trigger: Trigger<OnAdd, KnockedDown>,
+    settings: Res<RagdollSettings>,
+    mut commands: Commands,
+    enemies: Query<
+        (
+            &LinearVelocity,
+            &AngularVelocity,
+            Option<&Despawn>,
+            Option<&ChildOf>,
+        ),
+        With<Enemy>,
+    >,
+    children_q: Query<&Children>,
+    bones_q: Query<(&Name, &GlobalTransform)>,
+    skinned_meshes: Query<(), With<SkinnedMesh>>,
+    globals: Query<&GlobalTransform>,
+) {
+    if !settings.enabled {
+        return;
+    }
+    let enemy = trigger.target();
+    let Ok((linear_velocity, angular_velocity, despawn, child_of)) = enemies.get(enemy) else {
+        return;
+    };
+    let mut bones = HashMap::new();
+    for entity in children_q.iter_descendants(enemy) {
+        if skinned_meshes.contains(entity) {
+            // The bones are about to leave the enemy behind, and the mesh bounds with it.
+            commands.entity(entity).insert(NoFrustumCulling);
+        }
+        if let Ok((name, global)) = bones_q.get(entity) {
+            bones.insert(name.as_str(), (entity, *global));
+        }
+    }
+    if !bones.contains_key(SEGMENTS[0].bones[0]) {
+        // The scene hasn't spawned yet, it'll just tip over.
+        return;
+    }
+
+    // Bodies and joints live next to the enemy so they go away with it.
+    let parent = child_of.map(ChildOf::parent);
+    let parent_global = parent
+        .and_then(|parent| globals.get(parent).ok())
+        .copied()
+        .unwrap_or_default();
+    let scoped = |entity_cmds: &mut EntityCommands| {
+        if let Some(parent) = parent {
+            entity_cmds.insert(ChildOf(parent));
+        }
+        if let Some(despawn) = despawn {
+            entity_cmds.insert(despawn.clone());
+        }
+    };
+
+    let mut bodies = Vec::with_capacity(SEGMENTS.len());
+    for segment in &SEGMENTS {
+        let Some(&(_, body_global)) = bones.get(segment.bones[0]) else {
+            bodies.push(None);
+            continue;
+        };
+        let mut body_cmds = commands.spawn((
+            RagdollPart { enemy },
+            RigidBody::Dynamic,
+            Collider::compound(vec![(
+                Vec3::Y * segment.length / 2.0,
+                Quat::IDENTITY,
+                Collider::capsule(segment.radius, segment.length),
+            )]),
+            GameLayer::Ragdoll.collision_layers(),
+            *linear_velocity,
+            *angular_velocity,
+            body_global.reparented_to(&parent_global),
+        ));
+        scoped(&mut body_cmds);
+        let body = body_cmds.id();
+        for bone_name in segment.bones {
+            let Some(&(bone, bone_global)) = bones.get(bone_name) else {
+                continue;
+            };
+            commands
+                .entity(bone)
+                .remove::<AnimationTarget>()
+                .insert((ChildOf(body), bone_global.reparented_to(&body_global)));
+        }
+        bodies.push(Some((body, body_global)));
+    }
+
+    for (segment, child) in SEGMENTS.iter().zip(&bodies) {
+        let (Some(parent_index), Some((child, child_global))) = (segment.parent, child) else {
+            continue;
+        };
+        let Some((parent, parent_global)) = bodies[parent_index] else {
+            continue;
+        };
+        let (_, parent_rotation, parent_translation) =
+            parent_global.to_scale_rotation_translation();
+        let anchor = parent_rotation.inverse() * (child_global.translation() - parent_translation);
+        let mut joint_cmds = commands.spawn((
+            Name::new("Ragdoll Joint"),
+            SphericalJoint::new(parent, *child)
+                .with_local_anchor_1(anchor)
+                .with_swing_limits(-segment.limit, segment.limit)
+                .with_twist_limits(-segment.limit, segment.limit),
+        ));
+        scoped(&mut joint_cmds);
+    }
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_observer(ragdoll_on_knockdown);
+}
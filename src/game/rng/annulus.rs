@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use rand::Rng;
+use std::f32::consts::PI;
+
+/// Samples a uniformly random point in the ring between `inner_radius` and `outer_radius`,
+/// returning [`Vec2`].
+pub fn sample_point_in_annulus<R: Rng + ?Sized>(
+    rng: &mut R,
+    inner_radius: f32,
+    outer_radius: f32,
+) -> Vec2 {
+    // Pick r² uniformly so the outer edge, which has more area, gets more of the points.
+    let (inner_sq, outer_sq) = (inner_radius * inner_radius, outer_radius * outer_radius);
+    let r: f32 = rng
+        .random_range(inner_sq.min(outer_sq)..=inner_sq.max(outer_sq))
+        .sqrt();
+    let theta: f32 = rng.random_range(0.0..(2.0 * PI));
+    Vec2::from_angle(theta) * r
+}
+
+pub trait RandomAnnulusPoint {
+    fn random_annulus_point(&mut self, inner_radius: f32, outer_radius: f32) -> Vec2;
+}
+
+/// Any rng, e.g. [`GlobalEntropy`](bevy_rand::prelude::GlobalEntropy) or
+/// [`Entropy`](bevy_rand::prelude::Entropy).
+impl<R: Rng + ?Sized> RandomAnnulusPoint for R {
+    fn random_annulus_point(&mut self, inner_radius: f32, outer_radius: f32) -> Vec2 {
+        sample_point_in_annulus(self, inner_radius, outer_radius)
+    }
+}
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use rand::Rng;
+use std::f32::consts::PI;
+
+/// Samples a uniformly random point in the unit disc (radius = 1.0),
+/// returning [`Vec2`].
+pub fn sample_point_in_disc<R: Rng + ?Sized>(rng: &mut R) -> Vec2 {
+    // Taking the square root keeps the points from bunching up in the middle.
+    let r: f32 = rng.random_range(0.0..=1.0_f32).sqrt();
+    let theta: f32 = rng.random_range(0.0..(2.0 * PI));
+    Vec2::from_angle(theta) * r
+}
+
+pub trait RandomDiscPoint {
+    fn random_disc_point(&mut self, radius: f32) -> Vec2;
+}
+
+/// Any rng, e.g. [`GlobalEntropy`](bevy_rand::prelude::GlobalEntropy) or
+/// [`Entropy`](bevy_rand::prelude::Entropy).
+impl<R: Rng + ?Sized> RandomDiscPoint for R {
+    fn random_disc_point(&mut self, radius: f32) -> Vec2 {
+        sample_point_in_disc(self) * radius
+    }
+}
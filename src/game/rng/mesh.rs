@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+/// Samples uniformly random points on the surface of a set of triangles, e.g. a [`Mesh`] used
+/// as a spawn region. Bigger triangles get proportionally more of the points.
+#[derive(Debug, Clone)]
+pub struct TriangleSampler {
+    triangles: Vec<Triangle3d>,
+    /// Running total of the triangle areas, for picking a triangle by area.
+    cumulative_area: Vec<f32>,
+}
+
+impl TriangleSampler {
+    /// Returns [`None`] if the triangles don't have any area.
+    pub fn new(triangles: impl IntoIterator<Item = Triangle3d>) -> Option<Self> {
+        let mut total = 0.0;
+        let (triangles, cumulative_area) = triangles
+            .into_iter()
+            .filter(|triangle| triangle.area() > 0.0)
+            .map(|triangle| {
+                total += triangle.area();
+                (triangle, total)
+            })
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+        if triangles.is_empty() {
+            return None;
+        }
+        Some(Self {
+            triangles,
+            cumulative_area,
+        })
+    }
+
+    /// Samples the triangles of `mesh`, placed by `transform`.
+    pub fn from_mesh(mesh: &Mesh, transform: &GlobalTransform) -> Option<Self> {
+        Self::new(transformed_triangles(mesh, transform))
+    }
+
+    pub fn total_area(&self) -> f32 {
+        self.cumulative_area.last().copied().unwrap_or_default()
+    }
+
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let target = rng.random_range(0.0..self.total_area());
+        let ix = self
+            .cumulative_area
+            .partition_point(|&area| area <= target)
+            .min(self.triangles.len() - 1);
+        let [a, b, c] = self.triangles[ix].vertices;
+        let (mut u, mut v): (f32, f32) = (rng.random(), rng.random());
+        // Fold points from the other half of the parallelogram back into the triangle.
+        if u + v > 1.0 {
+            u = 1.0 - u;
+            v = 1.0 - v;
+        }
+        a + (b - a) * u + (c - a) * v
+    }
+}
+
+/// The triangles of `mesh` placed by `transform`, none if it isn't a triangle mesh.
+pub fn transformed_triangles<'a>(
+    mesh: &'a Mesh,
+    transform: &'a GlobalTransform,
+) -> impl Iterator<Item = Triangle3d> + 'a {
+    mesh.triangles().into_iter().flatten().map(|triangle| {
+        let [a, b, c] = triangle
+            .vertices
+            .map(|vertex| transform.transform_point(vertex));
+        Triangle3d::new(a, b, c)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::rng::Prng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn samples_stay_on_the_triangles() {
+        let sampler = TriangleSampler::new([
+            Triangle3d::new(Vec3::ZERO, Vec3::X, Vec3::Z),
+            // Degenerate, must never be picked.
+            Triangle3d::new(Vec3::Y, Vec3::Y, Vec3::Y),
+        ])
+        .unwrap();
+        let mut rng = Prng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let point = sampler.sample(&mut rng);
+            assert_eq!(point.y, 0.0);
+            assert!(point.x >= 0.0 && point.z >= 0.0 && point.x + point.z <= 1.0 + 1e-5);
+        }
+        assert_eq!(TriangleSampler::new([]).map(|s| s.total_area()), None);
+    }
+}
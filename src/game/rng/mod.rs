@@ -1,5 +1,10 @@
+pub mod annulus;
+pub mod disc;
 pub mod global;
+pub mod mesh;
+pub mod run_seed;
 pub mod sphere;
+pub mod streams;
 
 use bevy::prelude::*;
 use bevy_auto_plugin::auto_plugin::*;
@@ -19,5 +24,7 @@ impl Plugin for RngPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(EntropyPlugin::<Prng>::with_seed(ZERO_SEED));
         app.add_plugins(global::plugin);
+        app.add_plugins(run_seed::plugin);
+        app.add_plugins(streams::plugin);
     }
 }
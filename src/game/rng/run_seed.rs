@@ -0,0 +1,155 @@
+//! Picks the seed every run starts from, so two players with the same seed face the same run.
+
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_prng::WyRand;
+use rand::{RngCore, SeedableRng};
+
+use crate::game::rng::global::GlobalRng;
+use crate::game::rng::{SEED_LEN, Seed};
+use crate::game::screens::Screen;
+
+const SECS_PER_DAY: u64 = 60 * 60 * 24;
+
+/// Where the seed of the next run comes from. Picked in the seed menu.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Default, Copy, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub enum SeedSelection {
+    /// A fresh seed every run.
+    #[default]
+    Random,
+    Custom(Seed),
+    /// The same seed for everyone on a given (UTC) day.
+    Daily,
+}
+
+/// The seed of the current (or last) run.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Default, Copy, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct RunSeed {
+    pub seed: Seed,
+    /// Days since the unix epoch, for daily challenge runs.
+    pub daily: Option<u64>,
+}
+
+impl RunSeed {
+    /// Human readable description, e.g. `Daily 2025-06-01 - 1F3A09C2D4E5B687`.
+    pub fn label(&self) -> String {
+        match self.daily {
+            Some(day) => format!("Daily {} - {}", format_day(day), format_seed(self.seed)),
+            None => format_seed(self.seed),
+        }
+    }
+}
+
+/// Formats a seed as 16 hex digits.
+pub fn format_seed(seed: Seed) -> String {
+    format!("{:016X}", u64::from_be_bytes(seed))
+}
+
+/// Parses up to 16 hex digits as written by [`format_seed`]. Any other non empty text is hashed
+/// instead, so players can share words as seeds.
+pub fn parse_seed(text: &str) -> Option<Seed> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    if text.len() <= SEED_LEN * 2 {
+        if let Ok(value) = u64::from_str_radix(text, 16) {
+            return Some(value.to_be_bytes());
+        }
+    }
+    // FNV-1a, stable across platforms and releases unlike std's hashers.
+    let hash = text
+        .to_lowercase()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+    Some(hash.to_be_bytes())
+}
+
+/// The daily challenge seed for `day` days after the unix epoch.
+pub fn daily_seed(day: u64) -> Seed {
+    WyRand::seed_from_u64(day).next_u64().to_be_bytes()
+}
+
+/// Days since the unix epoch in UTC.
+pub fn today() -> u64 {
+    web_time::SystemTime::now()
+        .duration_since(web_time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() / SECS_PER_DAY)
+        .unwrap_or_default()
+}
+
+/// Formats days since the unix epoch as `YYYY-MM-DD`.
+pub fn format_day(day: u64) -> String {
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = day as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    format!("{y:04}-{m:02}-{d:02}")
+}
+
+/// Settles the seed before the level loads so every screen of the run can show it.
+pub fn resolve_run_seed(selection: Res<SeedSelection>, mut run_seed: ResMut<RunSeed>) {
+    *run_seed = match *selection {
+        SeedSelection::Random => RunSeed {
+            seed: rand::random(),
+            daily: None,
+        },
+        SeedSelection::Custom(seed) => RunSeed { seed, daily: None },
+        SeedSelection::Daily => {
+            let day = today();
+            RunSeed {
+                seed: daily_seed(day),
+                daily: Some(day),
+            }
+        }
+    };
+    info!("run seed: {}", run_seed.label());
+}
+
+pub fn apply_run_seed(run_seed: Res<RunSeed>, mut rng: GlobalRng) {
+    // Start every run from the seed itself, not wherever the previous run left the generator.
+    rng.reseed(run_seed.seed);
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::LoadLevel), resolve_run_seed);
+    app.add_systems(OnEnter(Screen::Gameplay), apply_run_seed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seed_round_trips_through_text() {
+        let seed = [0x1f, 0x3a, 0x09, 0xc2, 0xd4, 0xe5, 0xb6, 0x87];
+        assert_eq!(format_seed(seed), "1F3A09C2D4E5B687");
+        assert_eq!(parse_seed("1f3a09c2d4e5b687"), Some(seed));
+        assert_eq!(parse_seed("  42 "), Some([0, 0, 0, 0, 0, 0, 0, 0x42]));
+        assert_eq!(parse_seed("Zeus"), parse_seed("zeus"));
+        assert_ne!(parse_seed("zeus"), parse_seed("hades"));
+        assert_eq!(parse_seed(""), None);
+    }
+
+    #[test]
+    fn formats_days_as_dates() {
+        assert_eq!(format_day(0), "1970-01-01");
+        assert_eq!(format_day(11_016), "2000-02-29");
+        assert_eq!(format_day(20_240), "2025-06-01");
+    }
+}
@@ -1,12 +1,10 @@
-use crate::game::rng::Prng;
 use bevy::prelude::*;
-use bevy_rand::prelude::GlobalEntropy;
 use rand::Rng;
 use std::f32::consts::PI;
 
 /// Samples a uniformly random point on the unit sphere (radius = 1.0),
 /// returning [`Vec3`].
-pub fn sample_point_on_sphere<R: Rng>(rng: &mut R) -> Vec3 {
+pub fn sample_point_on_sphere<R: Rng + ?Sized>(rng: &mut R) -> Vec3 {
     // Pick z ∈ [-1, 1] uniformly, and θ ∈ [0, 2π) uniformly.
     let z: f32 = rng.random_range(-1.0..1.0);
     let theta: f32 = rng.random_range(0.0..(2.0 * PI));
@@ -20,11 +18,21 @@ pub fn sample_point_on_sphere<R: Rng>(rng: &mut R) -> Vec3 {
     Vec3::new(x, y, z)
 }
 
+/// Samples a uniformly random point inside the unit sphere (radius = 1.0),
+/// returning [`Vec3`].
+pub fn sample_point_in_sphere<R: Rng + ?Sized>(rng: &mut R) -> Vec3 {
+    // The cube root spreads the points evenly through the volume.
+    let r: f32 = rng.random_range(0.0..=1.0_f32).cbrt();
+    sample_point_on_sphere(rng) * r
+}
+
 pub trait RandomSpherePoint {
     fn random_sphere_point(&mut self, radius: f32) -> Vec3;
 }
 
-impl RandomSpherePoint for GlobalEntropy<'_, Prng> {
+/// Any rng, e.g. [`GlobalEntropy`](bevy_rand::prelude::GlobalEntropy) or
+/// [`Entropy`](bevy_rand::prelude::Entropy).
+impl<R: Rng + ?Sized> RandomSpherePoint for R {
     fn random_sphere_point(&mut self, radius: f32) -> Vec3 {
         sample_point_on_sphere(self) * radius
     }
@@ -0,0 +1,125 @@
+//! Independent RNG streams per gameplay subsystem.
+//!
+//! Every stream is forked from the global RNG right after it's reseeded for a run, so a stream only
+//! depends on the run seed and on how much that stream itself has been used. Picking one more sound
+//! variant can't shift the next wave.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_rand::prelude::{Entropy, ForkableRng};
+
+use crate::game::rng::Prng;
+use crate::game::rng::global::GlobalRng;
+use crate::game::rng::run_seed::apply_run_seed;
+use crate::game::screens::Screen;
+
+#[auto_register_type]
+#[derive(Component, Debug, Copy, Clone, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub enum RngStream {
+    /// Wave composition, spawn points and scatter.
+    Waves,
+    /// Enemy decisions.
+    EnemyAi,
+    /// Anything that doesn't affect the outcome of a run: sound variants, effects.
+    Cosmetic,
+    /// Drops and rewards.
+    Loot,
+}
+
+impl RngStream {
+    /// Fork order. Only ever append, reordering changes every stream of every seed.
+    pub const ALL: [Self; 4] = [Self::Waves, Self::EnemyAi, Self::Cosmetic, Self::Loot];
+}
+
+#[derive(SystemParam)]
+pub struct RngStreams<'w, 's> {
+    streams: Query<'w, 's, (&'static RngStream, &'static mut Entropy<Prng>)>,
+}
+
+impl RngStreams<'_, '_> {
+    pub fn rng(&mut self, stream: RngStream) -> Mut<'_, Entropy<Prng>> {
+        self.streams
+            .iter_mut()
+            .find_map(|(s, rng)| (*s == stream).then_some(rng))
+            .unwrap_or_else(|| panic!("missing rng stream {stream:?}"))
+    }
+}
+
+fn spawn_rng_streams(mut commands: Commands, mut global: GlobalRng) {
+    for stream in RngStream::ALL {
+        commands.spawn((
+            Name::new(format!("RngStream({stream:?})")),
+            stream,
+            global.rng().fork_rng(),
+        ));
+    }
+}
+
+/// Re-forks every stream from the global RNG. Runs after it has been reseeded for the run.
+pub fn fork_rng_streams(mut global: GlobalRng, mut streams: RngStreams) {
+    for stream in RngStream::ALL {
+        *streams.rng(stream) = global.rng().fork_rng();
+    }
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(Startup, spawn_rng_streams);
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        fork_rng_streams.after(apply_run_seed),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::rng::{RngPlugin, Seed};
+    use bevy::ecs::system::RunSystemOnce;
+    use rand::RngCore;
+
+    fn start_run(app: &mut App, seed: Seed) {
+        app.world_mut()
+            .run_system_once_with(
+                |In(seed): In<Seed>, mut rng: GlobalRng| rng.reseed(seed),
+                seed,
+            )
+            .unwrap();
+        app.world_mut().run_system_once(fork_rng_streams).unwrap();
+    }
+
+    fn next_u32(app: &mut App, stream: RngStream) -> u32 {
+        app.world_mut()
+            .run_system_once_with(
+                |In(stream): In<RngStream>, mut streams: RngStreams| streams.rng(stream).next_u32(),
+                stream,
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn streams_do_not_perturb_each_other() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(RngPlugin);
+        app.update();
+
+        start_run(&mut app, [3; 8]);
+        let waves = [
+            next_u32(&mut app, RngStream::Waves),
+            next_u32(&mut app, RngStream::Waves),
+        ];
+
+        start_run(&mut app, [3; 8]);
+        next_u32(&mut app, RngStream::Cosmetic);
+        next_u32(&mut app, RngStream::Cosmetic);
+        next_u32(&mut app, RngStream::Waves);
+        next_u32(&mut app, RngStream::Cosmetic);
+        assert_eq!(next_u32(&mut app, RngStream::Waves), waves[1]);
+
+        start_run(&mut app, [4; 8]);
+        assert_ne!(next_u32(&mut app, RngStream::Waves), waves[0]);
+    }
+}
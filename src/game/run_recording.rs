@@ -0,0 +1,311 @@
+//! Records the seed and per-tick [`PlayerInput`] of a whole run so it can be played back
+//! bit-exactly through the fixed timestep simulation.
+//!
+//! Native builds write a recording to [`RECORDINGS_DIR`] whenever a run ends. Pass its path to the
+//! `playback` binary to watch the run again, e.g. when it's attached to a bug report.
+
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game::levels::{SelectedDifficulty, SelectedLevel};
+use crate::game::pause_controller::Pause;
+use crate::game::player_input::{LatchPlayerInput, PlayerActions, PlayerInput};
+use crate::game::rng::Seed;
+use crate::game::rng::run_seed::{RunSeed, resolve_run_seed};
+use crate::game::screens::Screen;
+
+pub const RECORDINGS_DIR: &str = "recordings";
+const RECORDING_FORMAT_VERSION: u32 = 3;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunRecording {
+    pub version: u32,
+    pub seed: Seed,
+    /// Id of the [`Level`](crate::game::levels::Level) the run was played on.
+    pub level: String,
+    /// Id of the [`Difficulty`](crate::game::levels::Difficulty) the run was played on.
+    pub difficulty: String,
+    /// Fixed timestep the run was simulated with.
+    pub timestep: Duration,
+    /// Total number of simulated ticks.
+    pub ticks: u32,
+    /// Actions per tick, ticks without any input are left out.
+    pub inputs: Vec<(u32, PlayerActions)>,
+}
+
+impl RunRecording {
+    fn new(seed: Seed, level: &str, difficulty: &str, timestep: Duration) -> Self {
+        Self {
+            version: RECORDING_FORMAT_VERSION,
+            seed,
+            level: level.to_string(),
+            difficulty: difficulty.to_string(),
+            timestep,
+            ticks: 0,
+            inputs: Vec::new(),
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RunRecordingError> {
+        let contents = std::fs::read_to_string(path)?;
+        let recording: Self = ron::from_str(&contents)?;
+        if recording.version != RECORDING_FORMAT_VERSION {
+            return Err(RunRecordingError::Version(recording.version));
+        }
+        Ok(recording)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), RunRecordingError> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let contents = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum RunRecordingError {
+    Io(std::io::Error),
+    Parse(ron::error::SpannedError),
+    Serialize(ron::Error),
+    Version(u32),
+}
+
+impl Display for RunRecordingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "io error: {err}"),
+            Self::Parse(err) => write!(f, "invalid recording: {err}"),
+            Self::Serialize(err) => write!(f, "failed to serialize recording: {err}"),
+            Self::Version(version) => write!(
+                f,
+                "recording format v{version} is not supported (expected v{RECORDING_FORMAT_VERSION})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RunRecordingError {}
+
+impl From<std::io::Error> for RunRecordingError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ron::error::SpannedError> for RunRecordingError {
+    fn from(err: ron::error::SpannedError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl From<ron::Error> for RunRecordingError {
+    fn from(err: ron::Error) -> Self {
+        Self::Serialize(err)
+    }
+}
+
+/// Number of gameplay ticks simulated since the run started.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Default, Copy, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct RunClock(pub u32);
+
+/// The run currently being recorded.
+#[derive(Resource, Debug, Default, Clone)]
+struct RunRecorder(Option<RunRecording>);
+
+/// Replaces live input with a recorded run. Insert it before the run starts.
+#[derive(Resource, Debug, Clone)]
+pub struct RunPlayback {
+    recording: RunRecording,
+    cursor: usize,
+}
+
+impl RunPlayback {
+    pub fn new(recording: RunRecording) -> Self {
+        Self {
+            recording,
+            cursor: 0,
+        }
+    }
+    pub fn is_finished(&self, tick: u32) -> bool {
+        tick >= self.recording.ticks
+    }
+    fn actions_at(&mut self, tick: u32) -> PlayerActions {
+        let inputs = &self.recording.inputs;
+        while inputs.get(self.cursor).is_some_and(|(at, _)| *at < tick) {
+            self.cursor += 1;
+        }
+        match inputs.get(self.cursor) {
+            Some((at, actions)) if *at == tick => *actions,
+            _ => PlayerActions::default(),
+        }
+    }
+}
+
+fn start_run(
+    run_seed: Res<RunSeed>,
+    selected_level: Res<SelectedLevel>,
+    selected_difficulty: Res<SelectedDifficulty>,
+    mut clock: ResMut<RunClock>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+    mut recorder: ResMut<RunRecorder>,
+    playback: Option<ResMut<RunPlayback>>,
+) {
+    clock.0 = 0;
+    match playback {
+        Some(mut playback) => {
+            playback.cursor = 0;
+            fixed_time.set_timestep(playback.recording.timestep);
+            recorder.0 = None;
+            info!(
+                "playing back recorded run ({} ticks)",
+                playback.recording.ticks
+            );
+        }
+        None => {
+            recorder.0 = Some(RunRecording::new(
+                run_seed.seed,
+                selected_level.0,
+                selected_difficulty.0.id(),
+                fixed_time.timestep(),
+            ));
+        }
+    }
+}
+
+/// Replays the recorded seed whatever the seed menu says.
+fn use_playback_seed(playback: Option<Res<RunPlayback>>, mut run_seed: ResMut<RunSeed>) {
+    if let Some(playback) = playback {
+        run_seed.seed = playback.recording.seed;
+        run_seed.daily = None;
+    }
+}
+
+fn apply_playback(
+    clock: Res<RunClock>,
+    mut player_input: ResMut<PlayerInput>,
+    playback: Option<ResMut<RunPlayback>>,
+) {
+    let Some(mut playback) = playback else {
+        return;
+    };
+    if clock.0 == playback.recording.ticks {
+        info!("recorded run finished at tick {}", clock.0);
+    }
+    player_input.0 = playback.actions_at(clock.0);
+}
+
+fn record_input(
+    clock: Res<RunClock>,
+    player_input: Res<PlayerInput>,
+    mut recorder: ResMut<RunRecorder>,
+) {
+    let Some(recording) = recorder.0.as_mut() else {
+        return;
+    };
+    if !player_input.0.is_empty() {
+        recording.inputs.push((clock.0, player_input.0));
+    }
+    recording.ticks = clock.0 + 1;
+}
+
+fn advance_clock(mut clock: ResMut<RunClock>) {
+    clock.0 += 1;
+}
+
+fn finish_run(mut recorder: ResMut<RunRecorder>) {
+    let Some(recording) = recorder.0.take() else {
+        return;
+    };
+    #[cfg(not(target_family = "wasm"))]
+    {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let path = Path::new(RECORDINGS_DIR).join(format!("run-{timestamp}.ron"));
+        match recording.save(&path) {
+            Ok(()) => info!("saved run recording to {}", path.display()),
+            Err(err) => warn!("failed to save run recording to {}: {err}", path.display()),
+        }
+    }
+    #[cfg(target_family = "wasm")]
+    let _ = recording;
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.init_resource::<RunRecorder>();
+    app.add_systems(
+        OnEnter(Screen::LoadLevel),
+        use_playback_seed.after(resolve_run_seed),
+    );
+    app.add_systems(OnEnter(Screen::Gameplay), start_run);
+    app.add_systems(
+        FixedPreUpdate,
+        (apply_playback, record_input, advance_clock)
+            .chain()
+            .after(LatchPlayerInput)
+            .run_if(in_state(Pause(false)).and(in_state(Screen::Gameplay))),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), finish_run);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::player_input::PlayerAction;
+    use crate::game::rng::ZERO_SEED;
+
+    #[test]
+    fn playback_returns_recorded_actions_per_tick() {
+        let mut throw = PlayerActions::default();
+        throw.insert(PlayerAction::Throw);
+        let mut recording =
+            RunRecording::new(ZERO_SEED, "temple", "normal", Duration::from_micros(15625));
+        recording.inputs = vec![(2, throw), (5, throw)];
+        recording.ticks = 6;
+        let mut playback = RunPlayback::new(recording);
+        let actions = (0..7)
+            .map(|tick| playback.actions_at(tick))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actions,
+            vec![
+                PlayerActions::default(),
+                PlayerActions::default(),
+                throw,
+                PlayerActions::default(),
+                PlayerActions::default(),
+                throw,
+                PlayerActions::default(),
+            ]
+        );
+        assert!(playback.is_finished(6));
+    }
+
+    #[test]
+    fn round_trips_through_ron() {
+        let mut aim = PlayerActions::default();
+        aim.insert(PlayerAction::AimLeft);
+        aim.insert(PlayerAction::TurnRight);
+        let mut recording =
+            RunRecording::new([7; 8], "siege", "hard", Duration::from_micros(15625));
+        recording.inputs = vec![(0, aim), (42, aim)];
+        recording.ticks = 43;
+        let serialized = ron::to_string(&recording).unwrap();
+        let deserialized: RunRecording = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, recording);
+    }
+}
@@ -1,21 +1,23 @@
 use super::LevelData;
 use crate::game::behaviors::target_ent::TargetEnt;
+use crate::game::levels::SelectedLevel;
 use crate::game::pause_controller::Pause;
+use crate::game::physics::materials::PhysicsMaterial;
+use crate::game::player_input::{PlayerAction, PlayerInput};
 use crate::game::prefabs::enemy::Enemy;
 use crate::game::prefabs::game_world::GameWorld;
 use crate::game::prefabs::game_world_markers::{
     GameWorldMarkerSystemParam, auto_collider_mesh_obs,
 };
 use crate::game::prefabs::player::{Player, PlayerSystemParam};
+use crate::game::rng::streams::{RngStream, RngStreams};
 use crate::game::screens::Screen;
-use avian3d::prelude::{Friction, Mass};
 use bevy::pbr::CascadeShadowConfigBuilder;
 use bevy::prelude::*;
 use bevy::scene::SceneInstanceReady;
 use bevy_auto_plugin::auto_plugin::*;
 use itertools::Itertools;
 use smart_default::SmartDefault;
-use std::time::Duration;
 
 #[auto_register_type]
 #[auto_name]
@@ -47,50 +49,83 @@ pub fn spawn_level(mut commands: Commands) {
         ));
 }
 
+/// Whether [`spawn_over_time`] sends in waves by itself. The headless harness turns it off to place
+/// formations on its own.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, SmartDefault, Copy, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct AutoSpawnWaves(#[default(true)] pub bool);
+
 fn spawn_over_time(
     mut commands: Commands,
     mut game_world_marker: GameWorldMarkerSystemParam,
-    mut count_down: Local<Duration>,
-    mut wave: Local<usize>,
+    mut level_data: ResMut<LevelData>,
+    mut rng_streams: RngStreams,
+    selected_level: Res<SelectedLevel>,
     time: Res<Time>,
 ) {
-    *count_down = count_down.saturating_sub(time.delta());
-    if !count_down.is_zero() {
+    level_data.time_to_next_wave = level_data.time_to_next_wave.saturating_sub(time.delta());
+    if !level_data.time_to_next_wave.is_zero() {
         return;
     }
-    let time_between_waves = Duration::from_secs_f32(8.);
-    *count_down = time_between_waves;
-    if *wave == 0 {
-        *wave = 1;
-    }
+    let waves = selected_level.level().waves;
+    let difficulty = level_data.difficulty;
+    level_data.wave += 1;
+    let wave = level_data.wave;
+    level_data.time_to_next_wave = difficulty.time_between_waves(&waves, wave);
     info!("spawning enemies");
-    for ix in 0..1 {
-        let formation_id = game_world_marker
-            .spawn_in_enemy_spawn((Name::new(format!("SkeleGroup({})", *wave)),), None);
-        let (layout, layout_entries) = generate_pin_layout(3.0, 0.5, 3 + *wave, Facing::Toward);
-        let pin_entity_layout_tuples = layout_entries
-            .into_iter()
-            .map(|entry| {
-                let pin_id = commands
-                    .spawn((
-                        ChildOf(formation_id),
-                        Enemy::BaseSkele,
-                        Mass(1.0),
-                        Friction::new(0.4),
-                        TargetEnt {
-                            target_ent: game_world_marker.player_spawn.target_entity(),
-                        },
-                        Transform::from_scale(Vec3::splat(4.0))
-                            .with_translation(entry.pos.extend(0.)),
-                    ))
-                    .id();
-                let pin = Pin { entity: pin_id };
-                (pin, entry)
-            })
-            .collect_vec();
+    for ix in 0..difficulty.formations(&waves, wave) {
+        let Some(spawn) = game_world_marker
+            .enemy_spawns
+            .sample(&mut *rng_streams.rng(RngStream::Waves))
+        else {
+            warn!("no EnemySpawnMarker to spawn wave {wave} at");
+            return;
+        };
+        spawn_formation(
+            &mut commands,
+            &mut game_world_marker,
+            format!("SkeleGroup({wave}.{ix})"),
+            difficulty.rows(&waves, wave),
+            spawn,
+        );
     }
 }
 
+/// Spawns a triangle of enemies with `rows` rows at the world space `transform`, all heading for
+/// the player spawn. Returns the formation entity.
+pub fn spawn_formation(
+    commands: &mut Commands,
+    game_world_marker: &mut GameWorldMarkerSystemParam,
+    name: impl Into<std::borrow::Cow<'static, str>>,
+    rows: usize,
+    transform: Transform,
+) -> Entity {
+    let formation_id =
+        game_world_marker.spawn_in_world((Name::new(name),), transform.with_scale(Vec3::ONE));
+    let (layout, layout_entries) = generate_pin_layout(3.0, 0.5, rows, Facing::Toward);
+    let pin_entity_layout_tuples = layout_entries
+        .into_iter()
+        .map(|entry| {
+            let pin_id = commands
+                .spawn((
+                    ChildOf(formation_id),
+                    Enemy::BaseSkele,
+                    PhysicsMaterial::Pin,
+                    TargetEnt {
+                        target_ent: game_world_marker.player_spawn.target_entity(),
+                    },
+                    Transform::from_scale(Vec3::splat(4.0)).with_translation(entry.pos.extend(0.)),
+                ))
+                .id();
+            let pin = Pin { entity: pin_id };
+            (pin, entry)
+        })
+        .collect_vec();
+    formation_id
+}
+
 fn spawn_extras_on_instance_ready(
     trigger: Trigger<SceneInstanceReady>,
     mut commands: Commands,
@@ -102,7 +137,11 @@ fn spawn_extras_on_instance_ready(
     let player = game_world_marker.spawn_in_player_spawn(Player, None);
 }
 
-#[derive(Debug, SmartDefault)]
+/// Aim settings of the current run.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, SmartDefault, Reflect)]
+#[reflect(Resource)]
 struct PlayerData {
     #[default = 1.0]
     power: f32,
@@ -111,71 +150,69 @@ struct PlayerData {
     #[default = 30.0]
     turn_rate: f32,
 }
+
+fn reset_player_data(mut player_data: ResMut<PlayerData>) {
+    *player_data = PlayerData::default();
+}
+
 fn demo_input(
     time: Res<Time>,
     mut commands: Commands,
-    mut local: Local<(bool, PlayerData)>,
+    mut player_data: ResMut<PlayerData>,
     mut player_system_param: PlayerSystemParam,
     mut level_data: ResMut<LevelData>,
-    button_input: Res<ButtonInput<KeyCode>>,
+    player_input: Res<PlayerInput>,
 ) {
     let mut apply_transform = |transform: Transform| {
         commands
             .entity(player_system_param.entity())
             .insert(transform);
     };
-    let (changed, cache) = &mut *local;
+    let cache = &mut *player_data;
     let max_accuracy_offset: f32 = 30_f32.to_radians();
-    if button_input.pressed(KeyCode::ArrowLeft) {
+    if player_input.pressed(PlayerAction::AimLeft) {
         cache.accuracy += 1_f32.to_radians();
         cache.accuracy = cache
             .accuracy
             .clamp(-max_accuracy_offset, max_accuracy_offset);
-        *changed = true;
     }
-    if button_input.pressed(KeyCode::ArrowRight) {
+    if player_input.pressed(PlayerAction::AimRight) {
         cache.accuracy -= 1_f32.to_radians();
         cache.accuracy = cache
             .accuracy
             .clamp(-max_accuracy_offset, max_accuracy_offset);
-        *changed = true;
     }
-    if button_input.pressed(KeyCode::ArrowUp) {
+    if player_input.pressed(PlayerAction::PowerUp) {
         cache.power += 0.1;
-        *changed = true;
     }
-    if button_input.pressed(KeyCode::ArrowDown) {
+    if player_input.pressed(PlayerAction::PowerDown) {
         cache.power -= 0.1;
-        *changed = true;
     }
-    if button_input.pressed(KeyCode::KeyW) {
+    if player_input.pressed(PlayerAction::TurnRateUp) {
         cache.turn_rate += 1.0;
         cache.turn_rate = cache.turn_rate.max(1.0);
-        *changed = true;
     }
-    if button_input.pressed(KeyCode::KeyS) {
+    if player_input.pressed(PlayerAction::TurnRateDown) {
         cache.turn_rate -= 1.0;
         cache.turn_rate = cache.turn_rate.max(1.0);
-        *changed = true;
     }
-    if button_input.pressed(KeyCode::KeyA) {
+    if player_input.pressed(PlayerAction::TurnLeft) {
         let mut transform = player_system_param.player_transform.clone();
         transform.rotate(Quat::from_rotation_y(
             1_f32.to_radians() * cache.turn_rate * time.delta_secs(),
         ));
         apply_transform(transform);
-        *changed = true;
     }
-    if button_input.pressed(KeyCode::KeyD) {
+    if player_input.pressed(PlayerAction::TurnRight) {
         let mut transform = player_system_param.player_transform.clone();
         transform.rotate(Quat::from_rotation_y(
             -1_f32.to_radians() * cache.turn_rate * time.delta_secs(),
         ));
         apply_transform(transform);
     }
-    if button_input.just_pressed(KeyCode::Space) {
+    if player_input.pressed(PlayerAction::Throw) {
         if level_data.balls_left > 0 {
-            player_system_param.spawn_bowling_ball(cache.power, cache.accuracy);
+            player_system_param.throw_ball(cache.power, cache.accuracy);
             level_data.balls_left -= 1;
         }
     }
@@ -184,10 +221,14 @@ fn demo_input(
 #[auto_plugin(app=app)]
 pub(crate) fn plugin(app: &mut App) {
     app.add_systems(
-        Update,
-        (demo_input, spawn_over_time)
+        FixedUpdate,
+        (
+            demo_input,
+            spawn_over_time.run_if(resource_equals(AutoSpawnWaves(true))),
+        )
             .run_if(in_state(Pause(false)).and(in_state(Screen::Gameplay))),
     );
+    app.add_systems(OnEnter(Screen::LoadLevel), reset_player_data);
 }
 
 #[derive(Debug, Clone, Copy)]
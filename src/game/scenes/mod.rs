@@ -4,6 +4,10 @@ use bevy::prelude::*;
 use bevy_auto_plugin::auto_plugin::*;
 use smart_default::SmartDefault;
 
+use crate::game::levels::{Difficulty, SelectedDifficulty, SelectedLevel};
+use crate::game::prefabs::bowling_ball::BallType;
+use crate::game::screens::Screen;
+
 pub mod game;
 pub mod ui;
 
@@ -11,19 +15,51 @@ pub mod ui;
 #[auto_init_resource]
 #[derive(Resource, SmartDefault, Debug, Clone, Reflect)]
 pub struct LevelData {
+    /// What the run is being played on.
+    pub difficulty: Difficulty,
     #[default(5)]
     pub temple_health: usize,
+    /// What the temple started the level with.
+    #[default(5)]
+    pub max_temple_health: usize,
     pub kill_count: usize,
+    pub score: usize,
+    /// Knockdowns in a row, each within
+    /// [`COMBO_WINDOW`](crate::game::behaviors::knockdown::COMBO_WINDOW) of the last.
+    pub combo: usize,
+    pub combo_time_left: Duration,
+    pub ball_type: BallType,
     #[default(2)]
     pub balls_left: usize,
     #[default(Duration::from_secs_f32(8.0))]
     pub new_ball_rate: Duration,
     #[default(Duration::from_secs_f32(8.0))]
     pub time_to_new_ball: Duration,
+    /// Number of the last wave that was spawned.
+    pub wave: usize,
+    pub time_to_next_wave: Duration,
+}
+
+fn reset_level_data(
+    mut level_data: ResMut<LevelData>,
+    selected_level: Res<SelectedLevel>,
+    selected_difficulty: Res<SelectedDifficulty>,
+) {
+    let difficulty = selected_difficulty.0;
+    let temple_health = difficulty.temple_health(selected_level.level());
+    *level_data = LevelData {
+        difficulty,
+        temple_health,
+        max_temple_health: temple_health,
+        new_ball_rate: difficulty.ball_regen(),
+        time_to_new_ball: difficulty.ball_regen(),
+        ..default()
+    };
 }
 
 #[auto_plugin(app=app)]
 pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::LoadLevel), reset_level_data);
     app.add_plugins(game::plugin);
     app.add_plugins(ui::plugin);
 }
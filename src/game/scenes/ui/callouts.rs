@@ -0,0 +1,83 @@
+//! Knockdown feedback: the points for every skele that goes down, "xN CHAIN" as the combo climbs
+//! and "STRIKE!" when a whole formation falls.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::behaviors::knockdown::{EnemyKnockedDown, KnockedDown};
+use crate::game::pause_controller::PausableSystems;
+use crate::game::prefabs::enemy::Enemy;
+use crate::game::scenes::ui::floating_text::FloatingText;
+use crate::game::screens::Screen;
+use crate::game::theme::palette::CALLOUT_TEXT;
+
+/// Combos shorter than this don't get a callout.
+const MIN_CHAIN: usize = 2;
+/// Smaller groups going down together don't count as a strike.
+const MIN_STRIKE_PINS: usize = 3;
+
+fn knockdown_callouts(
+    mut commands: Commands,
+    mut knocked_down: EventReader<EnemyKnockedDown>,
+    parents: Query<&ChildOf>,
+    children_q: Query<&Children>,
+    enemies: Query<Has<KnockedDown>, With<Enemy>>,
+) {
+    let mut struck = HashSet::new();
+    // Only the furthest the combo got this frame is called out.
+    let mut chain: Option<&EnemyKnockedDown> = None;
+    for event in knocked_down.read() {
+        commands.trigger(FloatingText::new(
+            format!("+{}", event.points),
+            event.position,
+        ));
+        if event.combo >= MIN_CHAIN && chain.is_none_or(|chain| event.combo > chain.combo) {
+            chain = Some(event);
+        }
+
+        let Ok(formation) = parents.get(event.entity).map(ChildOf::parent) else {
+            continue;
+        };
+        let Ok(siblings) = children_q.get(formation) else {
+            continue;
+        };
+        let pins = siblings
+            .iter()
+            .filter_map(|entity| enemies.get(entity).ok())
+            .collect::<Vec<_>>();
+        if pins.len() >= MIN_STRIKE_PINS
+            && pins.iter().all(|&knocked_down| knocked_down)
+            && struck.insert(formation)
+        {
+            commands.trigger(
+                FloatingText::new("STRIKE!", event.position)
+                    .with_color(CALLOUT_TEXT)
+                    .with_font_size(56.0)
+                    .with_lifetime(Duration::from_millis(1500))
+                    .with_rise(12.0),
+            );
+        }
+    }
+    if let Some(chain) = chain {
+        commands.trigger(
+            FloatingText::new(format!("x{} CHAIN", chain.combo), chain.position)
+                .with_color(CALLOUT_TEXT)
+                .with_font_size(44.0)
+                .with_lifetime(Duration::from_millis(1200))
+                .with_rise(10.0),
+        );
+    }
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        knockdown_callouts
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PausableSystems),
+    );
+}
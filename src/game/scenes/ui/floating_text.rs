@@ -0,0 +1,163 @@
+//! Text that pops up over a point in the world, drifts upward and fades out.
+//!
+//! Trigger a [`FloatingText`] from anywhere during gameplay. Each one is a UI node that gets moved
+//! to wherever its world position lands on the [`MainCamera`]'s screen every frame.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::ui::Val::Px;
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::camera::MainCamera;
+use crate::game::pause_controller::PausableSystems;
+use crate::game::screens::Screen;
+use crate::game::theme::palette::FLOATING_TEXT;
+
+/// Text is centered within a box this wide, so it can be placed by its middle.
+const BOX_WIDTH: f32 = 600.0;
+
+/// Pops up `text` over `position`.
+#[auto_register_type]
+#[derive(Event, Debug, Clone, Reflect)]
+pub struct FloatingText {
+    pub text: String,
+    pub position: Vec3,
+    pub color: Color,
+    pub font_size: f32,
+    pub lifetime: Duration,
+    /// World units it drifts up over its lifetime.
+    pub rise: f32,
+}
+
+impl FloatingText {
+    pub fn new(text: impl Into<String>, position: Vec3) -> Self {
+        Self {
+            text: text.into(),
+            position,
+            color: FLOATING_TEXT,
+            font_size: 28.0,
+            lifetime: Duration::from_millis(900),
+            rise: 6.0,
+        }
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    pub fn with_lifetime(mut self, lifetime: Duration) -> Self {
+        self.lifetime = lifetime;
+        self
+    }
+
+    pub fn with_rise(mut self, rise: f32) -> Self {
+        self.rise = rise;
+        self
+    }
+}
+
+#[auto_register_type]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+struct FloatingTextAnchor {
+    position: Vec3,
+    rise: f32,
+    age: Duration,
+    lifetime: Duration,
+}
+
+impl FloatingTextAnchor {
+    fn progress(&self) -> f32 {
+        (self.age.as_secs_f32() / self.lifetime.as_secs_f32()).clamp(0.0, 1.0)
+    }
+}
+
+fn spawn_floating_text(trigger: Trigger<FloatingText>, mut commands: Commands) {
+    let event = trigger.event();
+    commands.spawn((
+        Name::new(format!("Floating Text \"{}\"", event.text)),
+        FloatingTextAnchor {
+            position: event.position,
+            rise: event.rise,
+            age: Duration::ZERO,
+            lifetime: event.lifetime,
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            width: Px(BOX_WIDTH),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        // Hidden until it's been placed for the first time.
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        StateScoped(Screen::Gameplay),
+        children![(
+            Text(event.text.clone()),
+            TextFont::from_font_size(event.font_size),
+            TextColor(event.color),
+            TextShadow::default(),
+            Pickable::IGNORE,
+        )],
+    ));
+}
+
+fn age_floating_text(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut anchors: Query<(Entity, &mut FloatingTextAnchor)>,
+) {
+    for (entity, mut anchor) in anchors.iter_mut() {
+        anchor.age += time.delta();
+        if anchor.age >= anchor.lifetime {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn place_floating_text(
+    camera: Single<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut anchors: Query<(&FloatingTextAnchor, &Children, &mut Node, &mut Visibility)>,
+    mut colors: Query<&mut TextColor>,
+) {
+    let (camera, camera_transform) = *camera;
+    for (anchor, children, mut node, mut visibility) in anchors.iter_mut() {
+        let progress = anchor.progress();
+        let position = anchor.position + Vec3::Y * anchor.rise * progress;
+        let Ok(viewport) = camera.world_to_viewport(camera_transform, position) else {
+            // Behind the camera.
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        *visibility = Visibility::Inherited;
+        node.left = Px(viewport.x - BOX_WIDTH / 2.0);
+        node.top = Px(viewport.y);
+        // Stays solid for the first half, then fades out.
+        let alpha = (2.0 - 2.0 * progress).min(1.0);
+        let mut colors = colors.iter_many_mut(children);
+        while let Some(mut color) = colors.fetch_next() {
+            color.0.set_alpha(alpha);
+        }
+    }
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_observer(spawn_floating_text);
+    app.add_systems(
+        Update,
+        (
+            age_floating_text.in_set(PausableSystems),
+            place_floating_text,
+        )
+            .chain()
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
@@ -2,11 +2,14 @@ use bevy::{
     image::{ImageLoaderSettings, ImageSampler},
     prelude::*,
     text::TextColor,
-    ui::{AlignItems, JustifyContent, Node, UiRect, Val},
+    ui::{AlignItems, Node, UiRect, Val},
 };
 use bevy_auto_plugin::auto_plugin::*;
 
-use crate::game::{asset_tracking::LoadResource, scenes::LevelData, screens::Screen};
+use crate::game::{
+    asset_tracking::LoadResource, prefabs::bowling_ball::BallType, rng::run_seed::RunSeed,
+    scenes::LevelData, screens::Screen, theme::widget,
+};
 
 #[auto_register_type]
 #[derive(Resource, Asset, Debug, Clone, Reflect)]
@@ -51,100 +54,248 @@ impl FromWorld for HudAssets {
     }
 }
 
-#[derive(Component, Clone, Copy)]
-pub struct TempleHealthUi;
+/// Something a [`HudText`] reads out of [`LevelData`].
+pub trait HudValue: PartialEq + Copy + Send + Sync + 'static {}
 
-#[derive(Component, Clone, Copy)]
-pub struct BallThrowsLeftUI;
+impl<T: PartialEq + Copy + Send + Sync + 'static> HudValue for T {}
 
+/// A HUD label bound to one value in [`LevelData`]. [`LevelData`] changes every frame while its
+/// timers tick, so the value is compared first and only formatted when it differs from what's
+/// shown.
 #[derive(Component, Clone, Copy)]
-pub struct BallTimerUI;
+pub struct HudText<T: HudValue> {
+    pub value: fn(&LevelData) -> T,
+    pub format: fn(T) -> String,
+    pub shown: Option<T>,
+}
 
+/// A HUD progress bar fill, sized to a 0-1 fraction of something in [`LevelData`].
 #[derive(Component, Clone, Copy)]
-pub struct KillCountUI;
+pub struct HudBar(pub fn(&LevelData) -> f32);
 
-fn update_temple_health(
+fn update_hud_text<T: HudValue>(
     level_data: Res<LevelData>,
-    temple_text: Single<&mut Text, With<TempleHealthUi>>,
+    mut texts: Query<(&mut HudText<T>, &mut Text)>,
 ) {
-    let mut text = temple_text.into_inner();
-    *text = Text::new(format!(" {}", level_data.temple_health));
+    for (mut hud_text, mut text) in texts.iter_mut() {
+        let value = (hud_text.value)(&level_data);
+        if hud_text.shown != Some(value) {
+            hud_text.shown = Some(value);
+            text.0 = (hud_text.format)(value);
+        }
+    }
 }
 
-fn update_kill_count(level_data: Res<LevelData>, text: Single<&mut Text, With<KillCountUI>>) {
-    let mut text = text.into_inner();
-    *text = Text::new(format!("{}", level_data.kill_count));
+fn update_hud_bars(level_data: Res<LevelData>, mut bars: Query<(&HudBar, &mut Node)>) {
+    for (hud_bar, mut node) in bars.iter_mut() {
+        let width = Val::Percent(100.0 * (hud_bar.0)(&level_data).clamp(0.0, 1.0));
+        if node.width != width {
+            node.width = width;
+        }
+    }
 }
 
-fn update_ball_count(
-    level_data: Res<LevelData>,
-    count: Single<&mut Text, With<BallThrowsLeftUI>>,
-    timer: Single<&mut Text, (With<BallTimerUI>, Without<BallThrowsLeftUI>)>,
-) {
-    let mut count_text = count.into_inner();
-    *count_text = Text::new(format!("{}", level_data.balls_left));
+fn temple_health_fraction(level_data: &LevelData) -> f32 {
+    if level_data.max_temple_health == 0 {
+        return 0.0;
+    }
+    level_data.temple_health as f32 / level_data.max_temple_health as f32
+}
 
-    let mut timer_text = timer.into_inner();
-    *timer_text = Text::new(format!(
-        "  ({:.2})s",
-        level_data.time_to_new_ball.as_secs_f32()
-    ));
+fn temple_health(level_data: &LevelData) -> (usize, usize) {
+    (level_data.temple_health, level_data.max_temple_health)
+}
+
+fn kill_count(level_data: &LevelData) -> usize {
+    level_data.kill_count
+}
+
+fn balls_left(level_data: &LevelData) -> usize {
+    level_data.balls_left
+}
+
+/// Tenths of a second, as precise as the label gets.
+fn ball_timer_tenths(level_data: &LevelData) -> u32 {
+    (level_data.time_to_new_ball.as_secs_f32() * 10.0).round() as u32
+}
+
+fn ball_type(level_data: &LevelData) -> BallType {
+    level_data.ball_type
+}
+
+fn wave(level_data: &LevelData) -> usize {
+    level_data.wave
+}
+
+/// In whole seconds, rounded up so it only reads 0 once the wave is due.
+fn next_wave_secs(level_data: &LevelData) -> u32 {
+    level_data.time_to_next_wave.as_secs_f32().ceil() as u32
+}
+
+fn score(level_data: &LevelData) -> usize {
+    level_data.score
+}
+
+fn combo(level_data: &LevelData) -> usize {
+    level_data.combo
+}
+
+fn temple_health_text((health, max_health): (usize, usize)) -> String {
+    format!("{health}/{max_health}")
+}
+
+fn count_text(count: usize) -> String {
+    count.to_string()
+}
+
+fn ball_timer_text(tenths: u32) -> String {
+    format!("({:.1}s)", tenths as f32 / 10.0)
+}
+
+fn ball_type_text(ball_type: BallType) -> String {
+    ball_type.label().to_string()
+}
+
+fn wave_text(wave: usize) -> String {
+    format!("Wave {wave}")
+}
+
+fn next_wave_text(secs: u32) -> String {
+    if secs == 0 {
+        return String::new();
+    }
+    format!("next in {secs}s")
+}
+
+fn score_text(score: usize) -> String {
+    format!("Score {score}")
+}
+
+fn combo_text(combo: usize) -> String {
+    if combo < 2 {
+        return String::new();
+    }
+    format!("x{combo} combo")
+}
+
+fn hud_text<T: HudValue>(value: fn(&LevelData) -> T, format: fn(T) -> String) -> impl Bundle {
+    (
+        Name::new("Hud Text"),
+        Text::default(),
+        TextColor::WHITE,
+        HudText {
+            value,
+            format,
+            shown: None,
+        },
+    )
+}
+
+fn hud_icon(image: Handle<Image>) -> impl Bundle {
+    (Name::new("Hud Icon"), ImageNode { image, ..default() })
+}
+
+/// Icon and value(s) sitting together in a [`hud_row`].
+fn hud_group(name: &'static str, children: impl Bundle) -> impl Bundle {
+    (
+        Name::new(name),
+        Node {
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            ..default()
+        },
+        children,
+    )
 }
 
-fn spawn_hud_elements(mut commands: Commands, hud_assets: Res<HudAssets>) {
+fn hud_row(name: &'static str, children: impl Bundle) -> impl Bundle {
+    (
+        Name::new(name),
+        Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(32.0),
+            ..default()
+        },
+        children,
+    )
+}
+
+fn spawn_hud_elements(mut commands: Commands, hud_assets: Res<HudAssets>, run_seed: Res<RunSeed>) {
     commands.spawn((
+        Name::new("Seed"),
+        Text::new(format!("Seed: {}", run_seed.label())),
+        TextFont::from_font_size(14.0),
+        TextColor(Color::srgba(1.0, 1.0, 1.0, 0.6)),
         Node {
-            width: Val::Px(800.0),
-            height: Val::Px(200.0),
-            left: Val::Px(20.0),
             position_type: PositionType::Absolute,
+            right: Val::Px(20.0),
+            bottom: Val::Px(10.0),
+            ..default()
+        },
+        StateScoped(Screen::Gameplay),
+    ));
+    commands.spawn((
+        Name::new("Hud"),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.0),
+            top: Val::Px(0.0),
             padding: UiRect::all(Val::Px(20.0)),
-            align_items: AlignItems::FlexStart,
-            align_content: AlignContent::FlexEnd,
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(10.0),
             ..default()
         },
         StateScoped(Screen::Gameplay),
         children![
-            // Temple health
-            ImageNode {
-                image: hud_assets.temple_image.clone(),
-                ..default()
-            },
-            (
-                Text::new(""),
-                TextColor::WHITE,
-                BorderColor(Color::BLACK),
-                TempleHealthUi,
-            ),
-            (Text::new("        "),), // spacer
-            // Kill count
-            ImageNode {
-                image: hud_assets.skull_image.clone(),
-                ..default()
-            },
-            (
-                Text::new(""),
-                TextColor::WHITE,
-                BorderColor(Color::BLACK),
-                KillCountUI,
-            ),
-            (Text::new("        "),), // spacer
-            // Ball count
-            ImageNode {
-                image: hud_assets.balling_ball_image.clone(),
-                ..default()
-            },
-            (
-                Text::new(""),
-                TextColor::WHITE,
-                BorderColor(Color::BLACK),
-                BallThrowsLeftUI,
+            hud_row(
+                "Status Row",
+                children![
+                    hud_group(
+                        "Temple Health",
+                        children![
+                            hud_icon(hud_assets.temple_image.clone()),
+                            widget::progress_bar_sized(
+                                Val::Px(120.0),
+                                Val::Px(14.0),
+                                HudBar(temple_health_fraction),
+                            ),
+                            hud_text(temple_health, temple_health_text),
+                        ],
+                    ),
+                    hud_group(
+                        "Kill Count",
+                        children![
+                            hud_icon(hud_assets.skull_image.clone()),
+                            hud_text(kill_count, count_text),
+                        ],
+                    ),
+                    hud_group(
+                        "Balls",
+                        children![
+                            hud_icon(hud_assets.balling_ball_image.clone()),
+                            hud_text(balls_left, count_text),
+                            hud_text(ball_timer_tenths, ball_timer_text),
+                            hud_text(ball_type, ball_type_text),
+                        ],
+                    ),
+                ],
             ),
-            (
-                Text::new("  "),
-                TextColor::WHITE,
-                BorderColor(Color::BLACK),
-                BallTimerUI,
+            hud_row(
+                "Progress Row",
+                children![
+                    hud_group(
+                        "Wave",
+                        children![
+                            hud_text(wave, wave_text),
+                            hud_text(next_wave_secs, next_wave_text)
+                        ],
+                    ),
+                    hud_group(
+                        "Score",
+                        children![hud_text(score, score_text), hud_text(combo, combo_text)],
+                    ),
+                ],
             ),
         ],
     ));
@@ -152,12 +303,18 @@ fn spawn_hud_elements(mut commands: Commands, hud_assets: Res<HudAssets>) {
 
 #[auto_plugin(app=app)]
 pub(crate) fn plugin(app: &mut App) {
-    app.load_resource::<HudAssets>();
+    app.load_resource_scoped::<HudAssets>(OnEnter(Screen::LoadLevel), OnEnter(Screen::Title));
     app.add_systems(OnEnter(Screen::Gameplay), spawn_hud_elements);
     app.add_systems(
         Update,
-        update_temple_health.run_if(in_state(Screen::Gameplay)),
+        (
+            update_hud_text::<usize>,
+            update_hud_text::<u32>,
+            update_hud_text::<(usize, usize)>,
+            update_hud_text::<BallType>,
+            update_hud_bars
+                .run_if(resource_changed::<LevelData>.or(any_match_filter::<Added<HudBar>>)),
+        )
+            .run_if(in_state(Screen::Gameplay)),
     );
-    app.add_systems(Update, update_kill_count.run_if(in_state(Screen::Gameplay)));
-    app.add_systems(Update, update_ball_count.run_if(in_state(Screen::Gameplay)));
 }
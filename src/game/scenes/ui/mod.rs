@@ -1,9 +1,19 @@
 use bevy::prelude::*;
 use bevy_auto_plugin::auto_plugin::*;
 
+pub mod callouts;
+pub mod floating_text;
 pub mod hud;
+pub mod offscreen_indicators;
+pub mod radar;
+pub mod threat;
 
 #[auto_plugin(app=app)]
 pub(crate) fn plugin(app: &mut App) {
+    app.add_plugins(callouts::plugin);
+    app.add_plugins(floating_text::plugin);
     app.add_plugins(hud::plugin);
+    app.add_plugins(offscreen_indicators::plugin);
+    app.add_plugins(radar::plugin);
+    app.add_plugins(threat::plugin);
 }
@@ -0,0 +1,194 @@
+//! Arrows around the edge of the screen pointing at enemy groups the [`MainCamera`] can't see,
+//! colored by how close they are to the temple.
+
+use bevy::prelude::*;
+use bevy::ui::Val::Px;
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::camera::MainCamera;
+use crate::game::scenes::ui::threat::{EnemyGroups, track_enemy_groups};
+use crate::game::screens::Screen;
+
+/// Indicators are centered within a box this big.
+const BOX_SIZE: Vec2 = Vec2::new(64.0, 48.0);
+/// Gap between the indicators and the edge of the screen.
+const EDGE_MARGIN: f32 = 12.0;
+
+/// Points at the enemy group `0`.
+#[auto_register_type]
+#[derive(Component, Debug, Copy, Clone, Reflect)]
+#[reflect(Component)]
+struct OffscreenIndicator(Entity);
+
+#[auto_register_type]
+#[derive(Component, Debug, Copy, Clone, Reflect)]
+#[reflect(Component)]
+struct IndicatorArrow;
+
+#[auto_register_type]
+#[derive(Component, Debug, Copy, Clone, Reflect)]
+#[reflect(Component)]
+struct IndicatorDistance;
+
+fn indicator(group: Entity) -> impl Bundle {
+    (
+        Name::new("Offscreen Indicator"),
+        OffscreenIndicator(group),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Px(BOX_SIZE.x),
+            height: Px(BOX_SIZE.y),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        StateScoped(Screen::Gameplay),
+        children![
+            (
+                Text::default(),
+                TextFont::from_font_size(28.0),
+                TextShadow::default(),
+                Pickable::IGNORE,
+                IndicatorArrow,
+            ),
+            (
+                Text::default(),
+                TextFont::from_font_size(14.0),
+                TextShadow::default(),
+                Pickable::IGNORE,
+                IndicatorDistance,
+            ),
+        ],
+    )
+}
+
+/// Pushes a point `direction` away from the middle of a `size` screen out to its edge, and picks
+/// an arrow for whichever edge it ends up on.
+fn clamp_to_edge(size: Vec2, direction: Vec2) -> (Vec2, &'static str) {
+    let half = (size / 2.0 - Vec2::splat(EDGE_MARGIN) - BOX_SIZE / 2.0).max(Vec2::ZERO);
+    let direction = direction.normalize_or(Vec2::Y);
+    let scale_x = if direction.x == 0.0 {
+        f32::INFINITY
+    } else {
+        half.x / direction.x.abs()
+    };
+    let scale_y = if direction.y == 0.0 {
+        f32::INFINITY
+    } else {
+        half.y / direction.y.abs()
+    };
+    let arrow = match (scale_x < scale_y, direction.x < 0.0, direction.y < 0.0) {
+        (true, true, _) => "<",
+        (true, false, _) => ">",
+        (false, _, true) => "^",
+        (false, _, false) => "v",
+    };
+    (size / 2.0 + direction * scale_x.min(scale_y), arrow)
+}
+
+fn update_offscreen_indicators(
+    mut commands: Commands,
+    enemy_groups: Res<EnemyGroups>,
+    camera: Single<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut indicators: Query<(
+        Entity,
+        &OffscreenIndicator,
+        &Children,
+        &mut Node,
+        &mut Visibility,
+    )>,
+    mut texts_q: Query<
+        (&mut Text, &mut TextColor, Has<IndicatorArrow>),
+        Or<(With<IndicatorArrow>, With<IndicatorDistance>)>,
+    >,
+) {
+    let (camera, camera_transform) = *camera;
+    let Some(size) = camera.logical_viewport_size() else {
+        return;
+    };
+    let mut spawned = Vec::new();
+    for (entity, indicator, children, mut node, mut visibility) in indicators.iter_mut() {
+        let Some(group) = enemy_groups
+            .groups
+            .iter()
+            .find(|group| group.entity == indicator.0)
+        else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        spawned.push(group.entity);
+        let on_screen = camera
+            .world_to_viewport(camera_transform, group.center)
+            .is_ok_and(|viewport| Rect::from_corners(Vec2::ZERO, size).contains(viewport));
+        if on_screen {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+        *visibility = Visibility::Inherited;
+
+        // Screen-space direction, y down, which still points the right way for things behind.
+        let local = camera_transform
+            .affine()
+            .inverse()
+            .transform_point3(group.center);
+        let (position, arrow) = clamp_to_edge(size, Vec2::new(local.x, -local.y));
+        node.left = Px(position.x - BOX_SIZE.x / 2.0);
+        node.top = Px(position.y - BOX_SIZE.y / 2.0);
+
+        let distance = enemy_groups
+            .temple
+            .map_or(0.0, |temple| group.center.distance(temple));
+        let mut texts = texts_q.iter_many_mut(children);
+        while let Some((mut text, mut color, is_arrow)) = texts.fetch_next() {
+            let value = if is_arrow {
+                arrow.to_string()
+            } else {
+                format!("{}x {distance:.0}m", group.count)
+            };
+            if text.0 != value {
+                text.0 = value;
+            }
+            color.0 = group.color();
+        }
+    }
+    for group in &enemy_groups.groups {
+        if !spawned.contains(&group.entity) {
+            commands.spawn(indicator(group.entity));
+        }
+    }
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        update_offscreen_indicators
+            .after(track_enemy_groups)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indicators_stick_to_the_edge_they_point_at() {
+        let size = Vec2::new(1280.0, 720.0);
+        let (right, arrow) = clamp_to_edge(size, Vec2::X);
+        assert_eq!(arrow, ">");
+        assert_eq!(right.y, size.y / 2.0);
+        assert_eq!(right.x, size.x - EDGE_MARGIN - BOX_SIZE.x / 2.0);
+
+        let (top, arrow) = clamp_to_edge(size, Vec2::new(0.1, -1.0));
+        assert_eq!(arrow, "^");
+        assert!((top.y - (EDGE_MARGIN + BOX_SIZE.y / 2.0)).abs() < 1e-3);
+
+        let (left, arrow) = clamp_to_edge(size, Vec2::new(-1.0, 0.2));
+        assert_eq!(arrow, "<");
+        assert!((left.x - (EDGE_MARGIN + BOX_SIZE.x / 2.0)).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,209 @@
+//! A top-down radar in the corner of the screen, centered on the temple and turned to face the
+//! same way as the [`MainCamera`]. Zeus and every enemy group show up as blips, the groups colored
+//! by how close they are to the temple.
+
+use bevy::prelude::*;
+use bevy::ui::Val::{Percent, Px};
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::camera::MainCamera;
+use crate::game::prefabs::player::Player;
+use crate::game::scenes::ui::threat::{EnemyGroups, track_enemy_groups};
+use crate::game::screens::Screen;
+use crate::game::theme::palette::{RADAR_BACKGROUND, RADAR_PLAYER, RADAR_TEMPLE};
+
+/// Diameter of the radar on screen.
+const RADAR_SIZE: f32 = 180.0;
+/// World distance from the temple to the rim of the radar. Anything further sits on the rim.
+const RADAR_RANGE: f32 = 100.0;
+const TEMPLE_BLIP_SIZE: f32 = 14.0;
+const PLAYER_BLIP_SIZE: f32 = 10.0;
+const GROUP_BLIP_MIN_SIZE: f32 = 6.0;
+const GROUP_BLIP_MAX_SIZE: f32 = 16.0;
+
+#[auto_register_type]
+#[derive(Component, Debug, Copy, Clone, Reflect)]
+#[reflect(Component)]
+struct Radar;
+
+/// Shows the enemy group `0` on the [`Radar`].
+#[auto_register_type]
+#[derive(Component, Debug, Copy, Clone, Reflect)]
+#[reflect(Component)]
+struct RadarGroupBlip(Entity);
+
+#[auto_register_type]
+#[derive(Component, Debug, Copy, Clone, Reflect)]
+#[reflect(Component)]
+struct RadarPlayerBlip;
+
+fn blip(size: f32, color: Color) -> impl Bundle {
+    (
+        Name::new("Radar Blip"),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Px(size),
+            height: Px(size),
+            left: Px((RADAR_SIZE - size) / 2.0),
+            top: Px((RADAR_SIZE - size) / 2.0),
+            ..default()
+        },
+        BackgroundColor(color),
+        BorderRadius::MAX,
+        Pickable::IGNORE,
+    )
+}
+
+fn spawn_radar(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Radar"),
+        Radar,
+        Node {
+            position_type: PositionType::Absolute,
+            left: Px(20.0),
+            bottom: Px(20.0),
+            width: Px(RADAR_SIZE),
+            height: Px(RADAR_SIZE),
+            overflow: Overflow::clip(),
+            ..default()
+        },
+        BackgroundColor(RADAR_BACKGROUND),
+        BorderRadius::MAX,
+        Pickable::IGNORE,
+        StateScoped(Screen::Gameplay),
+        children![
+            (
+                Name::new("Radar Cross"),
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Percent(50.0),
+                    width: Px(1.0),
+                    height: Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(RADAR_TEMPLE.with_alpha(0.2)),
+            ),
+            (
+                Name::new("Radar Cross"),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Percent(50.0),
+                    width: Percent(100.0),
+                    height: Px(1.0),
+                    ..default()
+                },
+                BackgroundColor(RADAR_TEMPLE.with_alpha(0.2)),
+            ),
+            blip(TEMPLE_BLIP_SIZE, RADAR_TEMPLE),
+            (blip(PLAYER_BLIP_SIZE, RADAR_PLAYER), RadarPlayerBlip),
+        ],
+    ));
+}
+
+/// Maps world positions onto the radar, with the camera's heading pointing up.
+struct RadarProjection {
+    center: Vec3,
+    forward: Vec2,
+    right: Vec2,
+}
+
+impl RadarProjection {
+    fn new(center: Vec3, camera_transform: &GlobalTransform) -> Self {
+        // Looking straight down the forward vector has no heading, but up does.
+        let forward = camera_transform.forward();
+        let forward = Vec2::new(forward.x, forward.z)
+            .try_normalize()
+            .or_else(|| {
+                let up = camera_transform.up();
+                Vec2::new(up.x, up.z).try_normalize()
+            })
+            .unwrap_or(Vec2::NEG_Y);
+        Self {
+            center,
+            forward,
+            right: Vec2::new(-forward.y, forward.x),
+        }
+    }
+
+    /// Top-left corner of a `size` blip at `position`.
+    fn place(&self, position: Vec3, size: f32) -> Vec2 {
+        let offset = (position - self.center).xz();
+        let scale = RADAR_SIZE / 2.0 / RADAR_RANGE;
+        let local = Vec2::new(offset.dot(self.right), -offset.dot(self.forward)) * scale;
+        let local = local.clamp_length_max(RADAR_SIZE / 2.0 - size / 2.0);
+        local + Vec2::splat((RADAR_SIZE - size) / 2.0)
+    }
+}
+
+fn update_radar(
+    mut commands: Commands,
+    enemy_groups: Res<EnemyGroups>,
+    camera: Single<&GlobalTransform, With<MainCamera>>,
+    radar: Single<Entity, With<Radar>>,
+    player: Query<&GlobalTransform, With<Player>>,
+    mut player_blip: Single<(&mut Node, &mut Visibility), With<RadarPlayerBlip>>,
+    mut group_blips: Query<
+        (Entity, &RadarGroupBlip, &mut Node, &mut BackgroundColor),
+        Without<RadarPlayerBlip>,
+    >,
+) {
+    let projection = RadarProjection::new(enemy_groups.temple.unwrap_or_default(), *camera);
+
+    let (player_node, player_visibility) = &mut *player_blip;
+    match player.iter().next() {
+        Some(player) => {
+            let corner = projection.place(player.translation(), PLAYER_BLIP_SIZE);
+            player_node.left = Px(corner.x);
+            player_node.top = Px(corner.y);
+            **player_visibility = Visibility::Inherited;
+        }
+        None => **player_visibility = Visibility::Hidden,
+    }
+
+    let mut placed = Vec::new();
+    for (entity, blip, mut node, mut background) in group_blips.iter_mut() {
+        let Some(group) = enemy_groups
+            .groups
+            .iter()
+            .find(|group| group.entity == blip.0)
+        else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        placed.push(group.entity);
+        let size = group_blip_size(group.count);
+        let corner = projection.place(group.center, size);
+        node.left = Px(corner.x);
+        node.top = Px(corner.y);
+        node.width = Px(size);
+        node.height = Px(size);
+        background.0 = group.color();
+    }
+    for group in &enemy_groups.groups {
+        if placed.contains(&group.entity) {
+            continue;
+        }
+        // Placed properly next frame, parked in the middle for now.
+        commands.spawn((
+            blip(group_blip_size(group.count), group.color()),
+            RadarGroupBlip(group.entity),
+            ChildOf(*radar),
+        ));
+    }
+}
+
+/// Bigger groups get bigger blips.
+fn group_blip_size(count: usize) -> f32 {
+    (GROUP_BLIP_MIN_SIZE + count as f32).min(GROUP_BLIP_MAX_SIZE)
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_radar);
+    app.add_systems(
+        Update,
+        update_radar
+            .after(track_enemy_groups)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
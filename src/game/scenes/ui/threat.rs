@@ -0,0 +1,122 @@
+//! Where the skeles are relative to the temple, shared by the off-screen indicators and the radar.
+
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::behaviors::knockdown::KnockedDown;
+use crate::game::prefabs::enemy::Enemy;
+use crate::game::prefabs::game_world_markers::TempleBase;
+use crate::game::screens::Screen;
+use crate::game::theme::palette::{THREAT_FAR, THREAT_NEAR};
+
+/// Groups closer to the temple than this are as urgent as it gets.
+const THREAT_NEAR_DISTANCE: f32 = 15.0;
+/// Groups further out than this aren't urgent at all.
+const THREAT_FAR_DISTANCE: f32 = 80.0;
+
+/// Skeles still standing in one formation.
+#[derive(Debug, Copy, Clone, Reflect)]
+pub struct EnemyGroup {
+    /// The formation, or the skele itself if it isn't in one.
+    pub entity: Entity,
+    pub center: Vec3,
+    pub count: usize,
+    /// 0 far out, 1 at the temple.
+    pub urgency: f32,
+}
+
+impl EnemyGroup {
+    pub fn color(&self) -> Color {
+        threat_color(self.urgency)
+    }
+}
+
+/// Standing enemy groups, refreshed every frame of gameplay.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Default, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct EnemyGroups {
+    pub groups: Vec<EnemyGroup>,
+    /// Where the [`TempleBase`] is, if there is one.
+    pub temple: Option<Vec3>,
+}
+
+fn urgency(distance: f32) -> f32 {
+    1.0 - ((distance - THREAT_NEAR_DISTANCE) / (THREAT_FAR_DISTANCE - THREAT_NEAR_DISTANCE))
+        .clamp(0.0, 1.0)
+}
+
+pub fn threat_color(urgency: f32) -> Color {
+    THREAT_FAR
+        .to_srgba()
+        .mix(&THREAT_NEAR.to_srgba(), urgency)
+        .into()
+}
+
+pub(super) fn track_enemy_groups(
+    mut enemy_groups: ResMut<EnemyGroups>,
+    enemies: Query<
+        (Entity, &GlobalTransform, Option<&ChildOf>),
+        (With<Enemy>, Without<KnockedDown>),
+    >,
+    temple: Query<&GlobalTransform, With<TempleBase>>,
+) {
+    let enemy_groups = &mut *enemy_groups;
+    enemy_groups.temple = temple.iter().next().map(GlobalTransform::translation);
+    enemy_groups.groups.clear();
+    for (entity, transform, child_of) in enemies.iter() {
+        let group_entity = child_of.map_or(entity, ChildOf::parent);
+        let translation = transform.translation();
+        match enemy_groups
+            .groups
+            .iter_mut()
+            .find(|group| group.entity == group_entity)
+        {
+            Some(group) => {
+                group.center += translation;
+                group.count += 1;
+            }
+            None => enemy_groups.groups.push(EnemyGroup {
+                entity: group_entity,
+                center: translation,
+                count: 1,
+                urgency: 0.0,
+            }),
+        }
+    }
+    for group in &mut enemy_groups.groups {
+        group.center /= group.count as f32;
+        group.urgency = enemy_groups
+            .temple
+            .map_or(0.0, |temple| urgency(group.center.distance(temple)));
+    }
+}
+
+fn clear_enemy_groups(mut enemy_groups: ResMut<EnemyGroups>) {
+    *enemy_groups = EnemyGroups::default();
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        track_enemy_groups.run_if(in_state(Screen::Gameplay)),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), clear_enemy_groups);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn urgency_ramps_up_toward_the_temple() {
+        assert_eq!(urgency(THREAT_FAR_DISTANCE * 2.0), 0.0);
+        assert_eq!(urgency(THREAT_FAR_DISTANCE), 0.0);
+        assert_eq!(urgency(THREAT_NEAR_DISTANCE), 1.0);
+        assert_eq!(urgency(0.0), 1.0);
+        let halfway = urgency((THREAT_NEAR_DISTANCE + THREAT_FAR_DISTANCE) / 2.0);
+        assert!((halfway - 0.5).abs() < 1e-5);
+    }
+}
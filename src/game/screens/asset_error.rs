@@ -0,0 +1,59 @@
+//! Shown instead of a loading screen that would never finish because an asset failed to load.
+
+use bevy::{ecs::spawn::SpawnWith, prelude::*};
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::{
+    asset_tracking::{ResourceHandles, any_asset_failed},
+    screens::Screen,
+    theme::prelude::*,
+};
+
+fn enter_asset_error_screen(mut next_screen: ResMut<NextState<Screen>>) {
+    next_screen.set(Screen::AssetError);
+}
+
+fn spawn_asset_error_screen(mut commands: Commands, resource_handles: Res<ResourceHandles>) {
+    let failures = resource_handles.failures().to_vec();
+    commands.spawn((
+        widget::ui_root("Asset Error Screen"),
+        BackgroundColor(Color::BLACK),
+        StateScoped(Screen::AssetError),
+        Children::spawn((
+            Spawn(widget::header("Failed to load assets")),
+            SpawnWith(move |parent: &mut ChildSpawner| {
+                for failure in failures {
+                    parent.spawn(widget::mini_header(failure.type_name));
+                    parent.spawn(widget::mini_label(failure.error));
+                }
+                parent.spawn(widget::button("Back to Title", back_to_title));
+                #[cfg(not(target_family = "wasm"))]
+                parent.spawn(widget::button("Exit", exit_app));
+            }),
+        )),
+    ));
+}
+
+/// Starting the level again from the title retries whatever failed.
+fn back_to_title(_: Trigger<Pointer<Click>>, mut next_screen: ResMut<NextState<Screen>>) {
+    next_screen.set(Screen::Title);
+}
+
+fn clear_asset_failures(mut resource_handles: ResMut<ResourceHandles>) {
+    resource_handles.clear_failures();
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn exit_app(_: Trigger<Pointer<Click>>, mut app_exit: EventWriter<AppExit>) {
+    app_exit.write(AppExit::Success);
+}
+
+#[auto_plugin(app=app)]
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::AssetError), spawn_asset_error_screen);
+    app.add_systems(OnExit(Screen::AssetError), clear_asset_failures);
+    app.add_systems(
+        Update,
+        enter_asset_error_screen.run_if(any_asset_failed.and(not(in_state(Screen::AssetError)))),
+    );
+}
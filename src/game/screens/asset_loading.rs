@@ -6,14 +6,30 @@ use bevy_auto_plugin::auto_plugin::*;
 
 use crate::game::{asset_tracking::ResourceHandles, screens::Screen, theme::prelude::*};
 
+#[auto_register_type]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct LoadedAssetsLabel;
+
 fn spawn_loading_screen(mut commands: Commands) {
     commands.spawn((
         widget::ui_root("Loading Screen"),
         StateScoped(Screen::Loading),
-        children![widget::label("Loading Assets...")],
+        children![
+            widget::label("Loading Assets..."),
+            (widget::mini_label(""), LoadedAssetsLabel),
+        ],
     ));
 }
 
+fn update_loaded_assets_label(
+    resource_handles: Res<ResourceHandles>,
+    mut label: Single<&mut Text, With<LoadedAssetsLabel>>,
+) {
+    let (finished, total) = resource_handles.progress();
+    label.0 = format!("{finished} / {total}");
+}
+
 fn enter_preload_colliders_screen(mut next_screen: ResMut<NextState<Screen>>) {
     next_screen.set(Screen::LoadLevel);
 }
@@ -25,6 +41,10 @@ pub fn all_assets_loaded(resource_handles: Res<ResourceHandles>) -> bool {
 #[auto_plugin(app=app)]
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::Loading), spawn_loading_screen);
+    app.add_systems(
+        Update,
+        update_loaded_assets_label.run_if(in_state(Screen::Loading)),
+    );
 
     app.add_systems(
         Update,
@@ -15,9 +15,16 @@ fn pause(mut next_pause: ResMut<NextState<Pause>>) {
     next_pause.set(Pause(true));
 }
 
+/// Darkens the game while it's paused.
+#[auto_register_type]
+#[derive(Component, Debug, Default, Copy, Clone, Reflect)]
+#[reflect(Component)]
+pub struct PauseOverlay;
+
 fn spawn_pause_overlay(mut commands: Commands) {
     commands.spawn((
         Name::new("Pause Overlay"),
+        PauseOverlay,
         Node {
             width: Percent(100.0),
             height: Percent(100.0),
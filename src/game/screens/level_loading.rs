@@ -1,34 +1,56 @@
-use crate::game::{scenes::game::spawn_level, screens::Screen, theme::prelude::*};
-use bevy::{prelude::*, scene::SceneInstance};
+use crate::game::{
+    load_progress::{LoadProgress, update_load_progress},
+    physics::collider_cache::collider_cache_settled,
+    prefabs::game_world::{GameWorld, load_game_world_assets},
+    scenes::game::spawn_level,
+    screens::{Screen, asset_loading::all_assets_loaded},
+    theme::prelude::*,
+};
+use bevy::{prelude::*, ui::Val::*};
 use bevy_auto_plugin::auto_plugin::*;
 
+#[auto_register_type]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct LoadProgressFill;
+
+#[auto_register_type]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct LoadStageLabel;
+
 fn spawn_loading_screen(mut commands: Commands) {
     commands.spawn((
         widget::ui_root("Loading Level"),
         BackgroundColor(Color::BLACK),
         StateScoped(Screen::LoadLevel),
-        children![widget::label("Loading Level...")],
+        children![
+            widget::label("Loading Level..."),
+            widget::progress_bar(LoadProgressFill),
+            (widget::mini_label(""), LoadStageLabel),
+        ],
     ));
 }
 
+fn update_loading_screen(
+    progress: Res<LoadProgress>,
+    mut fill: Single<&mut Node, With<LoadProgressFill>>,
+    mut stage_label: Single<&mut Text, With<LoadStageLabel>>,
+) {
+    fill.width = Percent(progress.fraction() * 100.0);
+    stage_label.0 = match progress.current_stage() {
+        Some(stage) => stage.description().to_string(),
+        None => "Ready".to_string(),
+    };
+}
+
 fn monitor_load_completion(
+    progress: Res<LoadProgress>,
     mut next_screen: ResMut<NextState<Screen>>,
-    scene_spawner: Res<SceneSpawner>,
-    scene_instances: Query<&SceneInstance>,
-    just_added_scenes: Query<(), (With<SceneRoot>, Without<SceneInstance>)>,
-    just_added_meshes: Query<(), Added<Mesh3d>>,
 ) {
-    // TODO should likely also listen on PipelineCache being fully built
-    // (https://github.com/bevyengine/bevy/blob/f47b1c00ee6c55f98f1858db6d6bc1fc1a4bed0e/examples/games/loading_screen.rs#L299)
-    info!("Added scenes: {}", just_added_scenes.iter().count());
-    if just_added_scenes.iter().count() > 0 || just_added_meshes.iter().count() > 0 {
+    if !progress.is_ready() {
         return;
     }
-    for scene_instance in scene_instances.iter() {
-        if !scene_spawner.instance_is_ready(**scene_instance) {
-            return;
-        }
-    }
     info!("level loaded -> GamePlay");
     next_screen.set(Screen::Gameplay);
 }
@@ -36,9 +58,28 @@ fn monitor_load_completion(
 #[auto_plugin(app=app)]
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::LoadLevel), spawn_loading_screen);
-    app.add_systems(OnEnter(Screen::LoadLevel), spawn_level);
+    app.add_systems(OnEnter(Screen::LoadLevel), load_game_world_assets);
+    #[cfg(all(feature = "dev", not(target_family = "wasm")))]
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        crate::game::physics::collider_cache::save_collider_cache,
+    );
+    // The level's prefabs need their resources as soon as its scene spawns, and its colliders the
+    // cache.
+    app.add_systems(
+        Update,
+        spawn_level.run_if(
+            in_state(Screen::LoadLevel)
+                .and(all_assets_loaded)
+                .and(collider_cache_settled)
+                .and(not(any_with_component::<GameWorld>)),
+        ),
+    );
     app.add_systems(
         Update,
-        monitor_load_completion.run_if(in_state(Screen::LoadLevel)),
+        (update_loading_screen, monitor_load_completion)
+            .chain()
+            .after(update_load_progress)
+            .run_if(in_state(Screen::LoadLevel)),
     );
 }
@@ -1,8 +1,9 @@
 //! The game's main screen states and transitions between them.
 
+mod asset_error;
 pub mod asset_loading;
 mod end;
-mod gameplay;
+pub mod gameplay;
 mod level_loading;
 mod skein_server;
 mod splash;
@@ -25,6 +26,8 @@ pub enum Screen {
     Gameplay,
     SkeinServer,
     End,
+    /// An asset failed to load, see [`ResourceHandles::failures`](crate::game::asset_tracking::ResourceHandles::failures).
+    AssetError,
 }
 
 #[auto_plugin(app=app)]
@@ -32,6 +35,7 @@ pub(super) fn plugin(app: &mut App) {
     app.add_plugins((
         end::plugin,
         gameplay::plugin,
+        asset_error::plugin,
         asset_loading::plugin,
         level_loading::plugin,
         splash::plugin,
@@ -0,0 +1,89 @@
+//! Settings kept between runs of the game.
+//!
+//! [`PersistentSettings`] is loaded from [`SETTINGS_PATH`] in the [`storage`] on startup and
+//! written back whenever it changes. The live values still sit in their own resources, like
+//! [`GlobalVolume`], and get copied over when they change.
+
+use std::collections::HashMap;
+
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+
+use crate::game::levels::LevelProgress;
+use crate::game::prefabs::ragdoll::RagdollSettings;
+use crate::game::storage;
+
+/// File name in the [`storage`].
+pub const SETTINGS_PATH: &str = "settings.ron";
+
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, SmartDefault, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct PersistentSettings {
+    #[default(1.0)]
+    pub master_volume: f32,
+    pub ragdolls: bool,
+    /// Set once the tutorial has been finished or skipped.
+    pub skip_tutorial: bool,
+    /// See [`LevelProgress::best_kills`].
+    pub best_kills: HashMap<String, usize>,
+}
+
+fn load_persistent_settings(
+    mut settings: ResMut<PersistentSettings>,
+    mut global_volume: ResMut<GlobalVolume>,
+    mut ragdolls: ResMut<RagdollSettings>,
+    mut progress: ResMut<LevelProgress>,
+) {
+    if let Some(loaded) = storage::load_ron(SETTINGS_PATH) {
+        *settings = loaded;
+    }
+    global_volume.volume = Volume::Linear(settings.master_volume);
+    ragdolls.enabled = settings.ragdolls;
+    progress.best_kills = settings.best_kills.clone();
+}
+
+fn collect_persistent_settings(
+    mut settings: ResMut<PersistentSettings>,
+    global_volume: Res<GlobalVolume>,
+    ragdolls: Res<RagdollSettings>,
+    progress: Res<LevelProgress>,
+) {
+    let collected = PersistentSettings {
+        master_volume: global_volume.volume.to_linear(),
+        ragdolls: ragdolls.enabled,
+        best_kills: progress.best_kills.clone(),
+        ..settings.clone()
+    };
+    settings.set_if_neq(collected);
+}
+
+fn save_persistent_settings(settings: Res<PersistentSettings>) {
+    // Nothing to write back right after loading.
+    if settings.is_added() {
+        return;
+    }
+    storage::save_ron(SETTINGS_PATH, &*settings);
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(Startup, load_persistent_settings);
+    app.add_systems(
+        Update,
+        (
+            collect_persistent_settings.run_if(
+                resource_changed::<GlobalVolume>
+                    .or(resource_changed::<RagdollSettings>)
+                    .or(resource_changed::<LevelProgress>),
+            ),
+            save_persistent_settings.run_if(resource_changed::<PersistentSettings>),
+        )
+            .chain(),
+    );
+}
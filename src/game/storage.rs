@@ -0,0 +1,104 @@
+//! Small RON files kept between runs of the game, like the settings and the high score table.
+//!
+//! Native builds keep them in the platform's data directory: `$XDG_DATA_HOME` (or
+//! `~/.local/share`) on Linux, `~/Library/Application Support` on macOS and `%APPDATA%` on
+//! Windows, each in a folder named after the crate. Web builds keep them in the browser's
+//! `localStorage`, keyed by file name.
+
+use bevy::prelude::*;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Reads `name` back, `None` if it was never saved or can't be read.
+pub fn load_ron<T: DeserializeOwned>(name: &str) -> Option<T> {
+    let contents = match read(name) {
+        Ok(contents) => contents?,
+        Err(err) => {
+            warn!("failed to read {name}: {err}");
+            return None;
+        }
+    };
+    match ron::from_str(&contents) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            warn!("ignoring invalid {name}: {err}");
+            None
+        }
+    }
+}
+
+/// Writes `value` to `name`, replacing what was there. Failures are only logged.
+pub fn save_ron<T: Serialize>(name: &str, value: &T) {
+    let contents = match ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()) {
+        Ok(contents) => contents,
+        Err(err) => {
+            warn!("failed to serialize {name}: {err}");
+            return;
+        }
+    };
+    if let Err(err) = write(name, &contents) {
+        warn!("failed to write {name}: {err}");
+    }
+}
+
+/// Falls back to the working directory when the platform doesn't say where data goes.
+#[cfg(not(target_family = "wasm"))]
+fn data_dir() -> std::path::PathBuf {
+    use std::path::PathBuf;
+
+    let var = |name| {
+        std::env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    };
+    let base = if cfg!(target_os = "windows") {
+        var("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        var("HOME").map(|home| home.join("Library/Application Support"))
+    } else {
+        var("XDG_DATA_HOME").or_else(|| var("HOME").map(|home| home.join(".local/share")))
+    };
+    match base {
+        Some(base) => base.join(env!("CARGO_PKG_NAME")),
+        None => PathBuf::from("."),
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn read(name: &str) -> Result<Option<String>, String> {
+    match std::fs::read_to_string(data_dir().join(name)) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn write(name: &str, contents: &str) -> Result<(), String> {
+    let dir = data_dir();
+    std::fs::create_dir_all(&dir).map_err(|err| err.to_string())?;
+    std::fs::write(dir.join(name), contents).map_err(|err| err.to_string())
+}
+
+#[cfg(target_family = "wasm")]
+fn local_storage() -> Result<web_sys::Storage, String> {
+    web_sys::window()
+        .ok_or("no window")?
+        .local_storage()
+        .map_err(|err| format!("{err:?}"))?
+        .ok_or_else(|| "localStorage is disabled".to_string())
+}
+
+#[cfg(target_family = "wasm")]
+fn read(name: &str) -> Result<Option<String>, String> {
+    local_storage()?
+        .get_item(name)
+        .map_err(|err| format!("{err:?}"))
+}
+
+#[cfg(target_family = "wasm")]
+fn write(name: &str, contents: &str) -> Result<(), String> {
+    local_storage()?
+        .set_item(name, contents)
+        .map_err(|err| format!("{err:?}"))
+}
@@ -14,3 +14,24 @@ pub const BUTTON_BACKGROUND: Color = Color::srgb(0.275, 0.400, 0.750);
 pub const BUTTON_HOVERED_BACKGROUND: Color = Color::srgb(0.384, 0.600, 0.820);
 /// #3d4999
 pub const BUTTON_PRESSED_BACKGROUND: Color = Color::srgb(0.239, 0.286, 0.600);
+
+/// #1f2440
+pub const PROGRESS_BAR_BACKGROUND: Color = Color::srgb(0.122, 0.141, 0.251);
+/// #4666bf
+pub const PROGRESS_BAR_FILL: Color = BUTTON_BACKGROUND;
+
+/// #ffffff
+pub const FLOATING_TEXT: Color = Color::WHITE;
+/// #ffc933
+pub const CALLOUT_TEXT: Color = Color::srgb(1.0, 0.788, 0.2);
+
+/// #ffe066
+pub const THREAT_FAR: Color = Color::srgb(1.0, 0.878, 0.4);
+/// #ff3b30
+pub const THREAT_NEAR: Color = Color::srgb(1.0, 0.231, 0.188);
+/// #101426cc
+pub const RADAR_BACKGROUND: Color = Color::srgba(0.063, 0.078, 0.149, 0.8);
+/// #ddd369
+pub const RADAR_TEMPLE: Color = LABEL_TEXT;
+/// #6299d1
+pub const RADAR_PLAYER: Color = BUTTON_HOVERED_BACKGROUND;
@@ -69,6 +69,36 @@ pub fn mini_label(text: impl Into<String>) -> impl Bundle {
     )
 }
 
+/// A horizontal progress bar. `fill` marks the inner node, whose width is the progress.
+pub fn progress_bar(fill: impl Bundle) -> impl Bundle {
+    progress_bar_sized(Px(380.0), Px(24.0), fill)
+}
+
+/// A [`progress_bar`] with a custom size.
+pub fn progress_bar_sized(width: Val, height: Val, fill: impl Bundle) -> impl Bundle {
+    (
+        Name::new("Progress Bar"),
+        Node {
+            width,
+            height,
+            ..default()
+        },
+        BackgroundColor(PROGRESS_BAR_BACKGROUND),
+        BorderRadius::MAX,
+        children![(
+            Name::new("Progress Bar Fill"),
+            Node {
+                width: Percent(0.0),
+                height: Percent(100.0),
+                ..default()
+            },
+            BackgroundColor(PROGRESS_BAR_FILL),
+            BorderRadius::MAX,
+            fill,
+        )],
+    )
+}
+
 /// A large rounded button with text and an action defined as an [`Observer`].
 pub fn button<E, B, M, I>(text: impl Into<String>, action: I) -> impl Bundle
 where
@@ -92,6 +122,29 @@ where
     )
 }
 
+/// A rounded button sized for a short word, with an action defined as an [`Observer`].
+pub fn button_medium<E, B, M, I>(text: impl Into<String>, action: I) -> impl Bundle
+where
+    E: Event,
+    B: Bundle,
+    I: IntoObserverSystem<E, B, M>,
+{
+    button_base(
+        text,
+        action,
+        (
+            Node {
+                width: Px(200.0),
+                height: Px(56.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BorderRadius::MAX,
+        ),
+    )
+}
+
 /// A small square button with text and an action defined as an [`Observer`].
 pub fn button_small<E, B, M, I>(text: impl Into<String>, action: I) -> impl Bundle
 where
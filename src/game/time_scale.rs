@@ -0,0 +1,228 @@
+//! Temporarily scales game time for slow-motion and hit-stop effects.
+//!
+//! Physics steps in the fixed timestep, which accumulates [`Time<Virtual>`], so scaling virtual
+//! time slows `Time<Physics>` down together with every other gameplay clock. Pausing is still
+//! handled by `physics::plugin`; effects simply stop ticking while [`Pause`] is active.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use smart_default::SmartDefault;
+
+use crate::game::behaviors::knockdown::EnemyKnockedDown;
+use crate::game::camera::CameraTarget;
+use crate::game::game_system_set::AppSystems;
+use crate::game::pause_controller::Pause;
+use crate::game::screens::Screen;
+
+/// The combined time scale of all active effects.
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Clone, SmartDefault, Reflect)]
+#[reflect(Resource)]
+pub struct TimeScale {
+    /// Scale currently applied to [`Time<Virtual>`].
+    #[default(1.0)]
+    current: f32,
+    effects: Vec<TimeScaleEffect>,
+}
+
+impl TimeScale {
+    pub fn current(&self) -> f32 {
+        self.current
+    }
+    pub fn is_active(&self) -> bool {
+        !self.effects.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Reflect)]
+struct TimeScaleEffect {
+    scale: f32,
+    remaining: Duration,
+    ease_out: Duration,
+    focus: Option<Entity>,
+}
+
+impl TimeScaleEffect {
+    fn scale(&self) -> f32 {
+        if self.remaining >= self.ease_out || self.ease_out.is_zero() {
+            return self.scale;
+        }
+        let t = self.remaining.as_secs_f32() / self.ease_out.as_secs_f32();
+        1.0 + (self.scale - 1.0) * t
+    }
+}
+
+/// Requests a temporary change of game speed. Trigger it with `commands.trigger(..)`.
+///
+/// Overlapping requests don't stack, the slowest one wins.
+#[auto_register_type]
+#[derive(Event, Debug, Copy, Clone, Reflect)]
+pub struct SlowMotion {
+    /// Game speed while the effect is active, `0.0` freezes the game.
+    pub scale: f32,
+    /// How long the effect lasts in real time.
+    pub duration: Duration,
+    /// Real time at the end of [`Self::duration`] spent easing back to normal speed.
+    pub ease_out: Duration,
+    /// World position the camera should frame while the effect is active.
+    pub focus: Option<Vec3>,
+}
+
+impl SlowMotion {
+    pub fn new(scale: f32, secs: f32) -> Self {
+        Self {
+            scale,
+            duration: Duration::from_secs_f32(secs),
+            ease_out: Duration::from_secs_f32(secs * 0.5),
+            focus: None,
+        }
+    }
+    /// Freezes the game for a few frames to sell the weight of an impact.
+    pub fn hit_stop(secs: f32) -> Self {
+        Self {
+            scale: 0.0,
+            duration: Duration::from_secs_f32(secs),
+            ease_out: Duration::ZERO,
+            focus: None,
+        }
+    }
+    pub fn with_focus(mut self, focus: Vec3) -> Self {
+        self.focus = Some(focus);
+        self
+    }
+}
+
+/// Temporary camera target spawned at the point of impact of a [`SlowMotion`] effect.
+#[auto_register_type]
+#[auto_name]
+#[derive(Component, Debug, Default, Copy, Clone, Reflect)]
+#[reflect(Component)]
+#[require(Transform)]
+pub struct ImpactFocus;
+
+fn on_slow_motion(
+    trigger: Trigger<SlowMotion>,
+    mut commands: Commands,
+    mut time_scale: ResMut<TimeScale>,
+) {
+    let request = trigger.event();
+    let focus = request.focus.map(|focus| {
+        commands
+            .spawn((
+                ImpactFocus,
+                CameraTarget,
+                Transform::from_translation(focus),
+                StateScoped(Screen::Gameplay),
+            ))
+            .id()
+    });
+    time_scale.effects.push(TimeScaleEffect {
+        scale: request.scale.max(0.0),
+        remaining: request.duration,
+        ease_out: request.ease_out.min(request.duration),
+        focus,
+    });
+}
+
+fn tick_time_scale(
+    mut commands: Commands,
+    real_time: Res<Time<Real>>,
+    mut time_scale: ResMut<TimeScale>,
+) {
+    if !time_scale.is_active() {
+        return;
+    }
+    let delta = real_time.delta();
+    let time_scale = &mut *time_scale;
+    time_scale.effects.retain_mut(|effect| {
+        effect.remaining = effect.remaining.saturating_sub(delta);
+        let done = effect.remaining.is_zero();
+        if done {
+            if let Some(focus) = effect.focus {
+                if let Ok(mut ec) = commands.get_entity(focus) {
+                    ec.despawn();
+                }
+            }
+        }
+        !done
+    });
+    let scale = time_scale
+        .effects
+        .iter()
+        .map(TimeScaleEffect::scale)
+        .reduce(f32::min)
+        .unwrap_or(1.0);
+    time_scale.current = scale;
+}
+
+fn apply_time_scale(time_scale: Res<TimeScale>, mut virtual_time: ResMut<Time<Virtual>>) {
+    if virtual_time.relative_speed() != time_scale.current {
+        virtual_time.set_relative_speed(time_scale.current);
+    }
+}
+
+fn reset_time_scale(mut time_scale: ResMut<TimeScale>) {
+    *time_scale = TimeScale::default();
+}
+
+/// Knockdowns that happen within this window of each other count as one chain reaction.
+const MULTI_KILL_WINDOW: Duration = Duration::from_millis(600);
+const MULTI_KILL_COUNT: usize = 3;
+
+#[auto_register_type]
+#[auto_init_resource]
+#[derive(Resource, Debug, Default, Clone, Reflect)]
+#[reflect(Resource)]
+struct MultiKillTracker {
+    recent: VecDeque<(Duration, Vec3)>,
+}
+
+fn trigger_on_multi_kill(
+    mut commands: Commands,
+    real_time: Res<Time<Real>>,
+    mut tracker: ResMut<MultiKillTracker>,
+    mut knocked_down: EventReader<EnemyKnockedDown>,
+) {
+    let now = real_time.elapsed();
+    for event in knocked_down.read() {
+        tracker.recent.push_back((now, event.position));
+    }
+    while tracker
+        .recent
+        .front()
+        .is_some_and(|(at, _)| now.saturating_sub(*at) > MULTI_KILL_WINDOW)
+    {
+        tracker.recent.pop_front();
+    }
+    if tracker.recent.len() < MULTI_KILL_COUNT {
+        return;
+    }
+    let centroid =
+        tracker.recent.iter().map(|(_, pos)| *pos).sum::<Vec3>() / tracker.recent.len() as f32;
+    tracker.recent.clear();
+    commands.trigger(SlowMotion::hit_stop(0.08));
+    commands.trigger(SlowMotion::new(0.25, 1.5).with_focus(centroid));
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_observer(on_slow_motion);
+    app.add_systems(
+        Update,
+        (
+            (tick_time_scale, apply_time_scale)
+                .chain()
+                .in_set(AppSystems::TickTimers),
+            trigger_on_multi_kill.in_set(AppSystems::Update),
+        )
+            .run_if(in_state(Pause(false)).and(in_state(Screen::Gameplay))),
+    );
+    app.add_systems(
+        OnExit(Screen::Gameplay),
+        (reset_time_scale, apply_time_scale).chain(),
+    );
+}
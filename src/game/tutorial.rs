@@ -0,0 +1,270 @@
+//! A guided first run: an overlay walks new players through aiming, throwing, watching the ball,
+//! knocking down a formation and holding off a wave, moving on as they actually do each of them.
+//!
+//! It shows up until it's been finished or skipped once, which is remembered in
+//! [`PersistentSettings`].
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use bevy_auto_plugin::auto_plugin::*;
+
+use crate::game::pause_controller::PausableSystems;
+use crate::game::player_input::{PlayerAction, PlayerInput};
+use crate::game::prefabs::player_throw::ReleaseBall;
+use crate::game::run_recording::RunPlayback;
+use crate::game::scenes::LevelData;
+use crate::game::screens::Screen;
+use crate::game::settings::PersistentSettings;
+use crate::game::theme::palette::{HEADER_TEXT, LABEL_TEXT, RADAR_BACKGROUND};
+use crate::game::theme::widget;
+
+/// How long the ball has to be watched before moving on.
+const WATCH_BALL_TIME: Duration = Duration::from_millis(2500);
+/// How long the sign-off stays up.
+const DONE_TIME: Duration = Duration::from_secs(4);
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Reflect)]
+pub enum TutorialStep {
+    #[default]
+    Aim,
+    Throw,
+    WatchBall,
+    KnockDown,
+    ProtectTemple,
+    Done,
+}
+
+impl TutorialStep {
+    /// Steps before [`TutorialStep::Done`].
+    const COUNT: usize = 5;
+
+    fn next(self) -> Self {
+        match self {
+            Self::Aim => Self::Throw,
+            Self::Throw => Self::WatchBall,
+            Self::WatchBall => Self::KnockDown,
+            Self::KnockDown => Self::ProtectTemple,
+            Self::ProtectTemple | Self::Done => Self::Done,
+        }
+    }
+
+    fn instructions(self) -> &'static str {
+        match self {
+            Self::Aim => "Turn Zeus with A/D and fine-tune your aim with the Left/Right arrows.",
+            Self::Throw => "Press Space to hurl a bowling ball. Up/Down arrows change its power.",
+            Self::WatchBall => "The camera follows the ball. Watch where it rolls!",
+            Self::KnockDown => "Knock down a skele. Fallen skeles topple the ones around them.",
+            Self::ProtectTemple => {
+                "Hold the skeles off the temple until the next wave. Mind the radar!"
+            }
+            Self::Done => "You're ready, Zeus!",
+        }
+    }
+}
+
+/// The tutorial running in the current level.
+#[auto_register_type]
+#[derive(Resource, Debug, Default, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct Tutorial {
+    pub step: TutorialStep,
+    pub time_in_step: Duration,
+    /// Wave that was out when [`TutorialStep::ProtectTemple`] started.
+    wave_at_step: usize,
+}
+
+impl Tutorial {
+    fn advance(&mut self, level_data: &LevelData) {
+        self.step = self.step.next();
+        self.time_in_step = Duration::ZERO;
+        self.wave_at_step = level_data.wave;
+    }
+}
+
+#[auto_register_type]
+#[derive(Component, Debug, Copy, Clone, Reflect)]
+#[reflect(Component)]
+struct TutorialOverlay;
+
+#[auto_register_type]
+#[derive(Component, Debug, Copy, Clone, Reflect)]
+#[reflect(Component)]
+struct TutorialProgressLabel;
+
+#[auto_register_type]
+#[derive(Component, Debug, Copy, Clone, Reflect)]
+#[reflect(Component)]
+struct TutorialInstructionsLabel;
+
+fn start_tutorial(
+    mut commands: Commands,
+    settings: Res<PersistentSettings>,
+    playback: Option<Res<RunPlayback>>,
+) {
+    if settings.skip_tutorial || playback.is_some() {
+        return;
+    }
+    commands.insert_resource(Tutorial::default());
+    commands.spawn((
+        Name::new("Tutorial"),
+        TutorialOverlay,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Percent(100.0),
+            top: Px(110.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        Pickable::IGNORE,
+        StateScoped(Screen::Gameplay),
+        children![(
+            Name::new("Tutorial Panel"),
+            Node {
+                width: Px(640.0),
+                padding: UiRect::all(Px(16.0)),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Px(10.0),
+                ..default()
+            },
+            BackgroundColor(RADAR_BACKGROUND),
+            BorderRadius::all(Px(12.0)),
+            children![
+                (
+                    Text::default(),
+                    TextFont::from_font_size(18.0),
+                    TextColor(LABEL_TEXT),
+                    TutorialProgressLabel,
+                ),
+                (
+                    Text::default(),
+                    TextFont::from_font_size(24.0),
+                    TextColor(HEADER_TEXT),
+                    TextLayout::new_with_justify(JustifyText::Center),
+                    TutorialInstructionsLabel,
+                ),
+                widget::button_medium("Skip", skip_tutorial),
+            ],
+        )],
+    ));
+}
+
+/// Takes the tutorial down for good.
+fn end_tutorial(
+    commands: &mut Commands,
+    settings: &mut PersistentSettings,
+    overlays: &Query<Entity, With<TutorialOverlay>>,
+) {
+    settings.skip_tutorial = true;
+    commands.remove_resource::<Tutorial>();
+    for overlay in overlays.iter() {
+        commands.entity(overlay).despawn();
+    }
+}
+
+fn skip_tutorial(
+    _: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    mut settings: ResMut<PersistentSettings>,
+    overlays: Query<Entity, With<TutorialOverlay>>,
+) {
+    end_tutorial(&mut commands, &mut settings, &overlays);
+}
+
+fn advance_tutorial(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut tutorial: ResMut<Tutorial>,
+    mut settings: ResMut<PersistentSettings>,
+    player_input: Res<PlayerInput>,
+    level_data: Res<LevelData>,
+    overlays: Query<Entity, With<TutorialOverlay>>,
+) {
+    tutorial.time_in_step += time.delta();
+    let done = match tutorial.step {
+        TutorialStep::Aim => [
+            PlayerAction::TurnLeft,
+            PlayerAction::TurnRight,
+            PlayerAction::AimLeft,
+            PlayerAction::AimRight,
+        ]
+        .into_iter()
+        .any(|action| player_input.pressed(action)),
+        // Moved on by the ball actually leaving Zeus's hand, see `tutorial_on_release`.
+        TutorialStep::Throw => false,
+        TutorialStep::WatchBall => tutorial.time_in_step >= WATCH_BALL_TIME,
+        TutorialStep::KnockDown => level_data.kill_count > 0,
+        TutorialStep::ProtectTemple => level_data.wave > tutorial.wave_at_step,
+        TutorialStep::Done => tutorial.time_in_step >= DONE_TIME,
+    };
+    if !done {
+        return;
+    }
+    if tutorial.step == TutorialStep::Done {
+        end_tutorial(&mut commands, &mut settings, &overlays);
+        return;
+    }
+    tutorial.advance(&level_data);
+}
+
+fn tutorial_on_release(
+    _: Trigger<ReleaseBall>,
+    tutorial: Option<ResMut<Tutorial>>,
+    level_data: Res<LevelData>,
+) {
+    let Some(mut tutorial) = tutorial else {
+        return;
+    };
+    if tutorial.step == TutorialStep::Throw {
+        tutorial.advance(&level_data);
+    }
+}
+
+fn update_tutorial_labels(
+    tutorial: Res<Tutorial>,
+    mut progress: Single<&mut Text, With<TutorialProgressLabel>>,
+    mut instructions: Single<
+        &mut Text,
+        (
+            With<TutorialInstructionsLabel>,
+            Without<TutorialProgressLabel>,
+        ),
+    >,
+) {
+    let step = tutorial.step as usize + 1;
+    let progress_text = if tutorial.step == TutorialStep::Done {
+        "Tutorial complete".to_string()
+    } else {
+        format!("Tutorial {step}/{}", TutorialStep::COUNT)
+    };
+    if progress.0 != progress_text {
+        progress.0 = progress_text;
+    }
+    let instructions_text = tutorial.step.instructions();
+    if instructions.0 != instructions_text {
+        instructions.0 = instructions_text.to_string();
+    }
+}
+
+/// A tutorial left halfway through starts over next time.
+fn stop_tutorial(mut commands: Commands) {
+    commands.remove_resource::<Tutorial>();
+}
+
+#[auto_plugin(app=app)]
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), start_tutorial);
+    app.add_systems(OnExit(Screen::Gameplay), stop_tutorial);
+    app.add_observer(tutorial_on_release);
+    app.add_systems(
+        Update,
+        (
+            advance_tutorial.in_set(PausableSystems),
+            update_tutorial_labels,
+        )
+            .chain()
+            .run_if(resource_exists::<Tutorial>.and(in_state(Screen::Gameplay))),
+    );
+}